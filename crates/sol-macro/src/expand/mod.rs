@@ -83,6 +83,7 @@ impl<'ast> ExpCtxt<'ast> {
         }
 
         self.visit_file(self.ast);
+        proc_macro_error::abort_if_dirty();
 
         if self.all_items.len() > 1 {
             self.resolve_custom_types();
@@ -116,7 +117,9 @@ impl<'ast> ExpCtxt<'ast> {
             Item::Struct(strukt) => r#struct::expand(self, strukt),
             Item::Udt(udt) => udt::expand(self, udt),
             Item::Variable(var_def) => var_def::expand(self, var_def),
-            Item::Import(_) | Item::Pragma(_) | Item::Using(_) => Ok(TokenStream::new()),
+            Item::Import(_) | Item::Pragma(_) | Item::Using(_) | Item::Verbatim(_) => {
+                Ok(TokenStream::new())
+            }
         }
     }
 }
@@ -247,6 +250,18 @@ impl<'ast> Visit<'ast> for ExpCtxt<'ast> {
         ast::visit::visit_item_function(self, function);
     }
 
+    fn visit_type(&mut self, ty: &'ast Type) {
+        if let Type::Array(array) = ty {
+            if array.has_size_expr() {
+                emit_error!(
+                    array.size.as_ref().unwrap().span(),
+                    "generic expressions are not supported in array type sizes"
+                );
+            }
+        }
+        ast::visit::visit_type(self, ty);
+    }
+
     fn visit_item_event(&mut self, event: &'ast ItemEvent) {
         self.overloaded_items
             .entry(event.name.as_string())
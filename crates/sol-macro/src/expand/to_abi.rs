@@ -85,8 +85,8 @@ impl ToAbi for ast::FunctionAttributes {
 
     fn to_dyn_abi(&self, _cx: &ExpCtxt<'_>) -> Self::DynAbi {
         match self.mutability() {
-            Some(ast::Mutability::Pure(_) | ast::Mutability::Constant(_)) => StateMutability::Pure,
-            Some(ast::Mutability::View(_)) => StateMutability::View,
+            Some(ast::Mutability::Pure(_)) => StateMutability::Pure,
+            Some(ast::Mutability::View(_) | ast::Mutability::Constant(_)) => StateMutability::View,
             Some(ast::Mutability::Payable(_)) => StateMutability::Payable,
             None => StateMutability::NonPayable,
         }
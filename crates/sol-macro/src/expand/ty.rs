@@ -78,6 +78,9 @@ pub(crate) fn rec_expand_type(ty: &Type, crates: &ExternCrates, tokens: &mut Tok
         Type::Mapping(ref mapping) => quote_spanned! {mapping.span()=>
             ::core::compile_error!("Mapping types are not supported here")
         },
+        Type::Fixed(span, _) | Type::Ufixed(span, _) => quote_spanned! {span=>
+            ::core::compile_error!("Fixed-point types are not supported yet")
+        },
 
         Type::Custom(ref custom) => return custom.to_tokens(tokens),
     };
@@ -147,6 +150,9 @@ pub(crate) fn rec_expand_rust_type(ty: &Type, crates: &ExternCrates, tokens: &mu
         Type::Mapping(ref mapping) => quote_spanned! {mapping.span()=>
             ::core::compile_error!("Mapping types are not supported here")
         },
+        Type::Fixed(span, _) | Type::Ufixed(span, _) => quote_spanned! {span=>
+            ::core::compile_error!("Fixed-point types are not supported yet")
+        },
 
         // Exhaustive fallback to `SolType::RustType`
         ref ty @ (Type::Int(..) | Type::Uint(..) | Type::Custom(_)) => {
@@ -177,6 +183,8 @@ pub(super) fn type_base_data_size(cx: &ExpCtxt<'_>, ty: &Type) -> usize {
         | Type::Bool(_)
         | Type::Int(..)
         | Type::Uint(..)
+        | Type::Fixed(..)
+        | Type::Ufixed(..)
         | Type::FixedBytes(..)
         | Type::Function(_) => 32,
 
@@ -302,6 +310,8 @@ impl fmt::Display for TypePrinter<'_> {
         match self.ty {
             Type::Int(_, None) => f.write_str("int256"),
             Type::Uint(_, None) => f.write_str("uint256"),
+            Type::Address(..) => f.write_str("address"),
+            Type::Function(_) => f.write_str("function"),
 
             Type::Array(array) => {
                 Self::new(self.cx, &array.ty).fmt(f)?;
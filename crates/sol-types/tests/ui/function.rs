@@ -46,8 +46,9 @@ sol! {
     function badReturn1() returns;
 }
 
+// OK
 sol! {
-    function badReturn2() returns();
+    function emptyReturn() returns();
 }
 
 // OK
@@ -1,7 +1,8 @@
 use crate::{kw, Modifier, Mutability, Override, SolPath, Spanned, VariableAttribute, Visibility};
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream, TokenTree};
 use std::{
     fmt,
+    hash::{Hash, Hasher},
     ops::{Deref, DerefMut},
 };
 use syn::{
@@ -17,8 +18,19 @@ use syn::{
 pub struct FunctionAttributes(pub Vec<FunctionAttribute>);
 
 impl fmt::Display for FunctionAttributes {
+    /// Formats the attributes in canonical order, regardless of the order in
+    /// which they were parsed: visibility, mutability, `virtual`, `override`,
+    /// and finally the modifiers in their original order.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for (i, attr) in self.0.iter().enumerate() {
+        let mut attrs = self.0.iter().collect::<Vec<_>>();
+        attrs.sort_by_key(|attr| match attr {
+            FunctionAttribute::Visibility(_) => 0,
+            FunctionAttribute::Mutability(_) => 1,
+            FunctionAttribute::Virtual(_) => 2,
+            FunctionAttribute::Override(_) => 3,
+            FunctionAttribute::Modifier(_) | FunctionAttribute::Unknown(_) => 4,
+        });
+        for (i, attr) in attrs.into_iter().enumerate() {
             if i > 0 {
                 f.write_str(" ")?;
             }
@@ -50,17 +62,50 @@ impl DerefMut for FunctionAttributes {
 
 impl Parse for FunctionAttributes {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
+        Self::parse_while(input, |input| !input.peek(kw::returns) && input.peek(Ident::peek_any))
+    }
+}
+
+impl FunctionAttributes {
+    /// Parses the attributes of a function type: only visibility and
+    /// mutability keywords, so that a following identifier is left for the
+    /// variable name.
+    pub(crate) fn parse_function_type(input: ParseStream<'_>) -> Result<Self> {
+        Self::parse_while(input, |input| {
+            let lookahead = input.lookahead1();
+            Visibility::peek(&lookahead) || Mutability::peek(&lookahead)
+        })
+    }
+
+    fn parse_while(
+        input: ParseStream<'_>,
+        mut peek: impl FnMut(ParseStream<'_>) -> bool,
+    ) -> Result<Self> {
         let mut attributes = Vec::<FunctionAttribute>::new();
-        while !input.is_empty() && !input.peek(kw::returns) && input.peek(Ident::peek_any) {
+        while !input.is_empty() && peek(input) {
             let attr: FunctionAttribute = input.parse()?;
-            if matches!(
-                attr,
-                FunctionAttribute::Visibility(_)
-                    | FunctionAttribute::Mutability(_)
-                    | FunctionAttribute::Virtual(_)
-            ) {
-                if let Some(prev) = attributes.iter().find(|a| **a == attr) {
-                    let mut e = Error::new(attr.span(), "duplicate attribute");
+            if !matches!(attr, FunctionAttribute::Modifier(_)) {
+                let discriminant = std::mem::discriminant(&attr);
+                if let Some(prev) =
+                    attributes.iter().find(|a| std::mem::discriminant(*a) == discriminant)
+                {
+                    let msg = match &attr {
+                        FunctionAttribute::Visibility(_) if *prev != attr => {
+                            format!("conflicting visibility attributes `{prev}` and `{attr}`")
+                        }
+                        FunctionAttribute::Mutability(m) if *prev != attr => {
+                            // `payable` first: "cannot be both `payable` and `view`"
+                            let (a, b): (&dyn fmt::Display, &dyn fmt::Display) =
+                                if matches!(m, Mutability::Payable(_)) {
+                                    (m, prev)
+                                } else {
+                                    (prev, m)
+                                };
+                            format!("a function cannot be both `{a}` and `{b}`")
+                        }
+                        _ => "duplicate attribute".into(),
+                    };
+                    let mut e = Error::new(attr.span(), msg);
                     e.combine(Error::new(prev.span(), "previous declaration is here"));
                     return Err(e);
                 }
@@ -103,6 +148,58 @@ impl FunctionAttributes {
         self.0.iter().find_map(FunctionAttribute::modifier)
     }
 
+    /// Returns an iterator over the modifier invocations, in source order.
+    pub fn modifiers(&self) -> impl Iterator<Item = &Modifier> {
+        self.0.iter().filter_map(FunctionAttribute::modifier)
+    }
+
+    /// Returns an iterator over the raw tokens of the unknown attributes, in
+    /// source order. See [`FunctionAttribute::Unknown`].
+    pub fn unknown(&self) -> impl Iterator<Item = &TokenStream> {
+        self.0.iter().filter_map(|attr| match attr {
+            FunctionAttribute::Unknown(tokens) => Some(tokens),
+            _ => None,
+        })
+    }
+
+    /// Replaces the modifier invocations with [unknown](FunctionAttribute::Unknown)
+    /// attributes, holding the tokens they were parsed from.
+    ///
+    /// `input` must be positioned at the start of the attributes, which are
+    /// parsed again to collect the tokens.
+    pub(crate) fn modifiers_to_unknown(&mut self, input: ParseStream<'_>) -> Result<()> {
+        for attr in &mut self.0 {
+            let start = input.fork();
+            input.parse::<FunctionAttribute>()?;
+            if matches!(attr, FunctionAttribute::Modifier(_)) {
+                let mut tokens = TokenStream::new();
+                while start.cursor() != input.cursor() {
+                    tokens.extend([start.parse::<TokenTree>()?]);
+                }
+                *attr = FunctionAttribute::Unknown(tokens);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the deprecation warnings for these attributes, as spans and
+    /// messages.
+    ///
+    /// Deprecated attributes are still parsed to support legacy code. This
+    /// currently only includes the `constant` mutability, which was removed
+    /// in Solidity 0.5.0.
+    pub fn warnings(&self) -> Vec<(Span, &'static str)> {
+        self.0
+            .iter()
+            .filter_map(|attr| match attr {
+                FunctionAttribute::Mutability(m @ Mutability::Constant(_)) => {
+                    Some((m.span(), "`constant` is deprecated for functions, use `view` instead"))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn has_external(&self) -> bool {
         self.0.iter().any(FunctionAttribute::is_external)
     }
@@ -133,7 +230,7 @@ impl FunctionAttributes {
 }
 
 /// A function attribute.
-#[derive(Clone, PartialEq, Eq, Hash)]
+#[derive(Clone)]
 pub enum FunctionAttribute {
     /// A [Visibility] attribute.
     Visibility(Visibility),
@@ -145,6 +242,40 @@ pub enum FunctionAttribute {
     Virtual(Token![virtual]),
     /// An [Override] attribute.
     Override(Override),
+    /// An attribute that is not modeled, kept as raw tokens.
+    ///
+    /// Only produced by [`ItemFunction::parse_tolerant`](crate::ItemFunction::parse_tolerant).
+    Unknown(TokenStream),
+}
+
+impl PartialEq for FunctionAttribute {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Visibility(a), Self::Visibility(b)) => a == b,
+            (Self::Mutability(a), Self::Mutability(b)) => a == b,
+            (Self::Modifier(a), Self::Modifier(b)) => a == b,
+            (Self::Virtual(_), Self::Virtual(_)) => true,
+            (Self::Override(a), Self::Override(b)) => a == b,
+            (Self::Unknown(a), Self::Unknown(b)) => a.to_string() == b.to_string(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for FunctionAttribute {}
+
+impl Hash for FunctionAttribute {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Visibility(visibility) => visibility.hash(state),
+            Self::Mutability(mutability) => mutability.hash(state),
+            Self::Modifier(modifier) => modifier.hash(state),
+            Self::Virtual(_) => {}
+            Self::Override(o) => o.hash(state),
+            Self::Unknown(tokens) => tokens.to_string().hash(state),
+        }
+    }
 }
 
 impl fmt::Display for FunctionAttribute {
@@ -155,6 +286,7 @@ impl fmt::Display for FunctionAttribute {
             Self::Virtual(_) => f.write_str("virtual"),
             Self::Override(o) => o.fmt(f),
             Self::Modifier(modifier) => modifier.fmt(f),
+            Self::Unknown(tokens) => tokens.fmt(f),
         }
     }
 }
@@ -167,6 +299,7 @@ impl fmt::Debug for FunctionAttribute {
             Self::Virtual(_) => f.write_str("Virtual"),
             Self::Override(o) => o.fmt(f),
             Self::Modifier(modifier) => modifier.fmt(f),
+            Self::Unknown(tokens) => f.debug_tuple("Unknown").field(&tokens.to_string()).finish(),
         }
     }
 }
@@ -216,6 +349,7 @@ impl Spanned for FunctionAttribute {
             Self::Virtual(v) => v.span,
             Self::Override(o) => o.span(),
             Self::Modifier(m) => m.span(),
+            Self::Unknown(tokens) => tokens.span(),
         }
     }
 
@@ -226,6 +360,7 @@ impl Spanned for FunctionAttribute {
             Self::Virtual(v) => v.span = span,
             Self::Override(o) => o.set_span(span),
             Self::Modifier(m) => m.set_span(span),
+            Self::Unknown(tokens) => tokens.set_span(span),
         }
     }
 }
@@ -304,3 +439,25 @@ impl FunctionAttribute {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ItemFunction;
+
+    #[test]
+    fn empty() {
+        let attrs = FunctionAttributes::default();
+        assert!(attrs.is_empty());
+        assert_eq!(attrs, FunctionAttributes::new());
+        assert_eq!(attrs.visibility(), None);
+        assert_eq!(attrs.mutability(), None);
+        assert!(!attrs.has_virtual() && !attrs.has_override(None) && !attrs.has_modifier(None));
+        assert_eq!(attrs.to_string(), "");
+        assert_eq!(syn::parse_str::<FunctionAttributes>(&attrs.to_string()).unwrap(), attrs);
+
+        let f = ItemFunction::builder("f").build().unwrap();
+        assert!(f.attributes.is_empty());
+        assert_eq!(f.to_string(), "function f();");
+    }
+}
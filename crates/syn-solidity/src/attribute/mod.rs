@@ -9,7 +9,7 @@ use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     token::Paren,
-    Result, Token,
+    Error, Result, Token,
 };
 
 mod function;
@@ -47,6 +47,53 @@ kw_enum! {
     }
 }
 
+/// The state mutability of a function, as it appears in the JSON ABI.
+///
+/// Unlike [`Mutability`], this has no span and includes the implicit
+/// `nonpayable` mutability.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum StateMutability {
+    /// `pure`
+    Pure,
+    /// `view`, or the deprecated `constant`.
+    View,
+    /// `payable`
+    Payable,
+    /// No mutability attribute.
+    #[default]
+    NonPayable,
+}
+
+impl fmt::Display for StateMutability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<Option<Mutability>> for StateMutability {
+    fn from(mutability: Option<Mutability>) -> Self {
+        match mutability {
+            Some(Mutability::Pure(_)) => Self::Pure,
+            // `constant` was an alias of `view` before it was removed in 0.5.0
+            Some(Mutability::View(_) | Mutability::Constant(_)) => Self::View,
+            Some(Mutability::Payable(_)) => Self::Payable,
+            None => Self::NonPayable,
+        }
+    }
+}
+
+impl StateMutability {
+    /// Returns the JSON ABI representation of the state mutability.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pure => "pure",
+            Self::View => "view",
+            Self::Payable => "payable",
+            Self::NonPayable => "nonpayable",
+        }
+    }
+}
+
 /// The `override` attribute.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Override {
@@ -83,11 +130,12 @@ impl Parse for Override {
         let override_token = input.parse()?;
         let this = if input.peek(Paren) {
             let content;
-            Self {
-                override_token,
-                paren_token: Some(parenthesized!(content in input)),
-                paths: content.parse_terminated(SolPath::parse, Token![,])?,
+            let paren_token = parenthesized!(content in input);
+            let paths = content.parse_terminated(SolPath::parse, Token![,])?;
+            if paths.is_empty() {
+                return Err(Error::new(paren_token.span.join(), "expected at least one base"));
             }
+            Self { override_token, paren_token: Some(paren_token), paths }
         } else {
             Self { override_token, paren_token: None, paths: Default::default() }
         };
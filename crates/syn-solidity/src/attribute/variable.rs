@@ -15,8 +15,17 @@ use syn::{
 pub struct VariableAttributes(pub Vec<VariableAttribute>);
 
 impl fmt::Display for VariableAttributes {
+    /// Formats the attributes in canonical order, regardless of the order in
+    /// which they were parsed: visibility, `constant` or `immutable`, and
+    /// `override`.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for (i, attr) in self.0.iter().enumerate() {
+        let mut attrs = self.0.iter().collect::<Vec<_>>();
+        attrs.sort_by_key(|attr| match attr {
+            VariableAttribute::Visibility(_) => 0,
+            VariableAttribute::Constant(_) | VariableAttribute::Immutable(_) => 1,
+            VariableAttribute::Override(_) => 2,
+        });
+        for (i, attr) in attrs.into_iter().enumerate() {
             if i > 0 {
                 f.write_str(" ")?;
             }
@@ -30,33 +39,28 @@ impl Parse for VariableAttributes {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let mut attributes = Vec::new();
         while let Ok(attribute) = input.parse::<VariableAttribute>() {
-            let error = |prev: &VariableAttribute| {
-                let mut e = Error::new(attribute.span(), "duplicate attribute");
+            let error = |prev: &VariableAttribute, msg: &str| {
+                let mut e = Error::new(attribute.span(), msg);
                 e.combine(Error::new(prev.span(), "previous declaration is here"));
                 e
             };
 
             // Only one of: `constant`, `immutable`
-            match attribute {
+            let conflicting = match attribute {
                 VariableAttribute::Constant(_) => {
-                    if let Some(prev) =
-                        attributes.iter().find(|a| matches!(a, VariableAttribute::Immutable(_)))
-                    {
-                        return Err(error(prev));
-                    }
+                    attributes.iter().find(|a| matches!(a, VariableAttribute::Immutable(_)))
                 }
                 VariableAttribute::Immutable(_) => {
-                    if let Some(prev) =
-                        attributes.iter().find(|a| matches!(a, VariableAttribute::Constant(_)))
-                    {
-                        return Err(error(prev));
-                    }
+                    attributes.iter().find(|a| matches!(a, VariableAttribute::Constant(_)))
                 }
-                _ => {}
+                _ => None,
+            };
+            if let Some(prev) = conflicting {
+                return Err(error(prev, "a variable cannot be both `constant` and `immutable`"));
             }
 
             if let Some(prev) = attributes.iter().find(|a| **a == attribute) {
-                return Err(error(prev));
+                return Err(error(prev, "duplicate attribute"));
             }
             attributes.push(attribute);
         }
@@ -0,0 +1,66 @@
+use proc_macro2::Span;
+use std::fmt;
+
+/// A specialized [`Result`](std::result::Result) type for the string parsing
+/// and validation helpers of this crate.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// An error returned by the string parsing and validation helpers of this
+/// crate, like [`parse_file`](crate::parse_file) or
+/// [`ItemFunction::validate_for_interface`](crate::ItemFunction::validate_for_interface).
+///
+/// Both variants wrap a [`syn::Error`], which carries the span of the
+/// offending tokens. [`Parse`](syn::parse::Parse) implementations keep
+/// returning [`syn::Error`] so that they compose with `syn`.
+#[derive(Clone, Debug)]
+pub enum Error {
+    /// The input is not valid Solidity syntax.
+    Parse(syn::Error),
+    /// The input is valid syntax, but it is rejected by a semantic check.
+    Validation(syn::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(e) | Self::Validation(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<syn::Error> for Error {
+    #[inline]
+    fn from(e: syn::Error) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl From<Error> for syn::Error {
+    #[inline]
+    fn from(e: Error) -> Self {
+        e.into_syn_error()
+    }
+}
+
+impl Error {
+    /// Returns the span of the tokens that caused the error.
+    pub fn span(&self) -> Span {
+        self.as_syn_error().span()
+    }
+
+    /// Returns a reference to the underlying [`syn::Error`].
+    pub const fn as_syn_error(&self) -> &syn::Error {
+        match self {
+            Self::Parse(e) | Self::Validation(e) => e,
+        }
+    }
+
+    /// Converts the error into the underlying [`syn::Error`].
+    pub fn into_syn_error(self) -> syn::Error {
+        match self {
+            Self::Parse(e) | Self::Validation(e) => e,
+        }
+    }
+}
@@ -1,13 +1,13 @@
-use crate::{Item, Spanned};
-use proc_macro2::Span;
-use std::fmt;
+use crate::{FunctionKind, Item, Printer, Spanned};
+use proc_macro2::{Span, TokenStream, TokenTree};
+use std::{collections::HashSet, fmt, io};
 use syn::{
     parse::{Parse, ParseStream},
-    Attribute, Result,
+    Attribute, Expr, ExprLit, Lit, Meta, MetaNameValue, Result, Token,
 };
 
 /// A Solidity file. The root of the AST.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct File {
     /// The inner attributes of the file.
     pub attrs: Vec<Attribute>,
@@ -16,14 +16,40 @@ pub struct File {
 }
 
 impl fmt::Display for File {
+    /// Formats the file with the default [`Printer`], without the trailing
+    /// newline.
+    ///
+    /// See the [`pretty`](crate::pretty) module for which parts of the file
+    /// can be printed back as Solidity source code.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for (i, item) in self.items.iter().enumerate() {
-            if i > 0 {
-                f.write_str("\n\n")?;
-            }
-            item.fmt(f)?;
+        f.write_str(Printer::default().print_file(self).trim_end_matches('\n'))
+    }
+}
+
+/// Consumes the tokens of an item: everything up to and including the next
+/// `;` or brace-delimited group.
+fn skip_item(input: ParseStream<'_>) -> Result<TokenStream> {
+    let mut tokens = TokenStream::new();
+    while !input.is_empty() {
+        let end = input.peek(Token![;]) || input.peek(syn::token::Brace);
+        let tt: TokenTree = input.parse()?;
+        tokens.extend([tt]);
+        if end {
+            break;
         }
-        Ok(())
+    }
+    Ok(tokens)
+}
+
+/// Returns the contents of a `#[doc = "..."]` attribute.
+pub(crate) fn doc_comment(attr: &Attribute) -> Option<String> {
+    match &attr.meta {
+        Meta::NameValue(MetaNameValue {
+            path,
+            value: Expr::Lit(ExprLit { lit: Lit::Str(s), .. }),
+            ..
+        }) if path.is_ident("doc") => Some(s.value()),
+        _ => None,
     }
 }
 
@@ -40,6 +66,128 @@ impl Parse for File {
     }
 }
 
+impl File {
+    /// Parses a file like [`Parse`] does, but keeps going after an item fails
+    /// to parse, instead of aborting the whole file.
+    ///
+    /// The tokens of the unrecognized item, up to and including the next `;`
+    /// or `{ ... }` block, are kept as an [`Item::Verbatim`], and parsing
+    /// resumes after them. The file may be empty.
+    pub fn parse_tolerant(input: ParseStream<'_>) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_inner)?;
+        let mut items = Vec::new();
+        while !input.is_empty() {
+            let item = if input.fork().parse::<Item>().is_ok() {
+                input.parse()?
+            } else {
+                Item::Verbatim(skip_item(input)?)
+            };
+            items.push(item);
+        }
+        Ok(Self { attrs, items })
+    }
+
+    /// Creates a new, empty file.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an item to the file.
+    ///
+    /// Pragma directives are inserted after the pragmas already in the file,
+    /// but before any other item, so that they always come first.
+    pub fn push_item(&mut self, item: Item) {
+        if matches!(item, Item::Pragma(_)) {
+            let i = self.items.iter().position(|item| !matches!(item, Item::Pragma(_)));
+            self.items.insert(i.unwrap_or(self.items.len()), item);
+        } else {
+            self.items.push(item);
+        }
+    }
+
+    /// Writes the file as Solidity source code to `w`, using its [`Display`]
+    /// implementation, followed by a newline.
+    ///
+    /// [`Display`]: fmt::Display
+    pub fn write_to(&self, w: &mut impl io::Write) -> io::Result<()> {
+        writeln!(w, "{self}")
+    }
+
+    /// Returns the signature and selector of every function in the file,
+    /// including those in contracts, in source order.
+    ///
+    /// Identical signatures are only included once. Only [externally
+    /// callable](crate::ItemFunction::is_externally_callable) functions declared in
+    /// contracts are included: free functions, which are always internal,
+    /// modifiers, and functions with arguments that are not ABI-encodable
+    /// are skipped.
+    pub fn function_selectors(&self) -> Vec<(String, [u8; 4])> {
+        collect_unique(&self.items, |item, in_contract| match item {
+            Item::Function(f)
+                if in_contract
+                    && matches!(f.kind, FunctionKind::Function(_))
+                    && f.is_externally_callable() =>
+            {
+                f.signature().ok().map(|s| (crate::utils::selector(&s), s))
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the signature and selector of every error in the file,
+    /// including those in contracts, in source order.
+    ///
+    /// Identical signatures are only included once.
+    pub fn error_selectors(&self) -> Vec<(String, [u8; 4])> {
+        collect_unique(&self.items, |item, _| match item {
+            Item::Error(e) => Some((e.selector(), e.signature())),
+            _ => None,
+        })
+    }
+
+    /// Returns the signature and topic of every event in the file, including
+    /// those in contracts, in source order.
+    ///
+    /// Identical signatures are only included once. Anonymous events are
+    /// skipped, as they have no topic.
+    pub fn event_topics(&self) -> Vec<(String, [u8; 32])> {
+        collect_unique(&self.items, |item, _| match item {
+            Item::Event(e) if !e.is_anonymous() => Some((e.selector(), e.signature())),
+            _ => None,
+        })
+    }
+}
+
+/// Collects the `(signature, hash)` pairs returned by `f` for `items` and
+/// the items of their contracts, deduplicating signatures. `f` is also told
+/// whether the item is declared in a contract.
+fn collect_unique<T>(
+    items: &[Item],
+    f: impl Fn(&Item, bool) -> Option<(T, String)>,
+) -> Vec<(String, T)> {
+    fn collect<T>(
+        items: &[Item],
+        f: &impl Fn(&Item, bool) -> Option<(T, String)>,
+        in_contract: bool,
+        seen: &mut HashSet<String>,
+        out: &mut Vec<(String, T)>,
+    ) {
+        for item in items {
+            if let Item::Contract(contract) = item {
+                collect(&contract.body, f, true, seen, out);
+            } else if let Some((hash, signature)) = f(item, in_contract) {
+                if seen.insert(signature.clone()) {
+                    out.push((signature, hash));
+                }
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    collect(items, &f, false, &mut HashSet::new(), &mut out);
+    out
+}
+
 impl Spanned for File {
     fn span(&self) -> Span {
         self.items.span()
@@ -49,3 +197,197 @@ impl Spanned for File {
         self.items.set_span(span);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Item;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn tolerant() {
+        let src = r#"
+            function f(uint256 a) external returns (bool);
+            object "Token" { code { sstore(0, caller()) } }
+        "#;
+        syn::parse_str::<File>(src).unwrap_err();
+        let file = crate::parse_file_tolerant(src).unwrap();
+        let [Item::Function(f), Item::Verbatim(tokens)] = &file.items[..] else {
+            panic!("{:?}", file.items)
+        };
+        assert_eq!(f.name(), "f");
+        assert!(tokens.to_string().starts_with("object \"Token\""));
+        assert_eq!(file.items[1].span().start().line, 3);
+
+        let file = crate::parse_file_tolerant("x y z; x { } event E(); ").unwrap();
+        assert_eq!(file.items.len(), 3);
+        assert!(
+            matches!(&file.items[0], Item::Verbatim(tokens) if tokens.to_string() == "x y z ;")
+        );
+        assert!(matches!(&file.items[1], Item::Verbatim(tokens) if tokens.to_string() == "x { }"));
+        assert!(matches!(&file.items[2], Item::Event(_)));
+
+        assert!(crate::parse_file_tolerant("").unwrap().items.is_empty());
+    }
+
+    #[test]
+    fn items() {
+        let file: File = syn::parse_str(
+            r#"
+            pragma solidity ^0.8.0;
+            import "./IERC20.sol";
+
+            /// A point.
+            struct Point { uint256 x; uint256 y; }
+            enum Status { Pending, Active }
+            type Decimal is int256;
+            error Unauthorized();
+            event Moved(Point to);
+            function helper() pure returns (uint256);
+
+            /// A contract.
+            contract Foo {
+                function bar() external;
+            }
+            "#,
+        )
+        .unwrap();
+
+        let count = |f: fn(&Item) -> bool| file.items.iter().filter(|item| f(item)).count();
+        assert_eq!(file.items.len(), 9);
+        assert_eq!(count(|i| matches!(i, Item::Pragma(_))), 1);
+        assert_eq!(count(|i| matches!(i, Item::Import(_))), 1);
+        assert_eq!(count(|i| matches!(i, Item::Struct(_))), 1);
+        assert_eq!(count(|i| matches!(i, Item::Enum(_))), 1);
+        assert_eq!(count(|i| matches!(i, Item::Udt(_))), 1);
+        assert_eq!(count(|i| matches!(i, Item::Error(_))), 1);
+        assert_eq!(count(|i| matches!(i, Item::Event(_))), 1);
+        assert_eq!(count(|i| matches!(i, Item::Function(_))), 1);
+        assert_eq!(count(|i| matches!(i, Item::Contract(_))), 1);
+
+        let Item::Struct(point) = &file.items[2] else { panic!("{:?}", file.items[2]) };
+        assert_eq!(point.attrs.len(), 1);
+    }
+
+    #[test]
+    fn builder() {
+        let mut file = File::new();
+        file.push_item(syn::parse_str("function f(uint256 a) external returns (bool);").unwrap());
+        file.push_item(Item::Function(crate::ItemFunction::builder("g").build().unwrap()));
+        file.push_item(syn::parse_str("pragma solidity ^0.8.0;").unwrap());
+        assert_eq!(file.items.len(), 3);
+        assert!(matches!(file.items[0], Item::Pragma(_)));
+
+        let mut out = Vec::new();
+        file.write_to(&mut out).unwrap();
+        let s = String::from_utf8(out).unwrap();
+        assert!(s.starts_with("pragma solidity"), "{s}");
+        assert!(s.ends_with(";\n"), "{s}");
+        assert_eq!(s.matches("\n\n").count(), 2, "{s}");
+
+        let reparsed: File = syn::parse_str(&s).unwrap();
+        assert_eq!(reparsed.items.len(), 3);
+        assert_eq!(reparsed.to_string(), file.to_string());
+        assert_eq!(reparsed.function_selectors(), file.function_selectors());
+    }
+
+    #[test]
+    fn selectors() {
+        let file: File = syn::parse_str(
+            "
+            error Unauthorized();
+            function helper(uint x) pure returns (uint);
+
+            contract A {
+                event Transfer(address indexed from, address indexed to, uint256 value);
+                error Unauthorized();
+                function transfer(address to, uint amount) external returns (bool);
+                function helper(uint256 y) external;
+                function f(mapping(uint => uint) storage m) internal;
+                function g(uint x) internal;
+                function h(uint x) private;
+                modifier onlyOwner(address owner);
+                fallback() external;
+            }
+
+            contract B {
+                event Transfer(address indexed from, address indexed to, uint256 value);
+                event Anonymous() anonymous;
+                error InsufficientBalance(uint256 available, uint256 required);
+                function approve(address spender, uint256 amount) external returns (bool);
+            }
+            ",
+        )
+        .unwrap();
+
+        let functions = file.function_selectors();
+        let signatures = functions.iter().map(|(s, _)| s.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            signatures,
+            ["transfer(address,uint256)", "helper(uint256)", "approve(address,uint256)"]
+        );
+        for (signature, selector) in &functions {
+            let f = crate::parse_function(&format!("function {signature};")).unwrap();
+            assert_eq!(*selector, f.selector().unwrap(), "{signature}");
+        }
+        assert_eq!(functions[0].1, [0xa9, 0x05, 0x9c, 0xbb]);
+
+        let errors = file.error_selectors();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0], ("Unauthorized()".to_string(), [0x82, 0xb4, 0x29, 0x00]));
+        assert_eq!(errors[1].0, "InsufficientBalance(uint256,uint256)");
+
+        let events = file.event_topics();
+        assert_eq!(events.len(), 1);
+        let Item::Contract(b) = &file.items[3] else { panic!("{:?}", file.items[3]) };
+        let Item::Event(transfer) = &b.body[0] else { panic!("{:?}", b.body[0]) };
+        assert_eq!(events[0], (transfer.signature(), transfer.selector()));
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let file: File = syn::parse_str(
+            r#"
+            pragma solidity ^0.8.0;
+
+            /// A point.
+            /// @dev Two coordinates.
+            struct Point { uint256 x; uint256 y; }
+
+            /** Moves the point. */
+            event Moved(Point to);
+
+            contract Foo {
+                /// Does the thing.
+                function bar() external;
+            }
+            "#,
+        )
+        .unwrap();
+
+        let s = file.to_string();
+        assert!(s.contains("/// A point.\n/// @dev Two coordinates.\nstruct Point"), "{s}");
+        assert!(s.contains("{\n    /// Does the thing.\n    function bar() external;\n}"), "{s}");
+
+        let reparsed: File = syn::parse_str(&s).unwrap();
+        assert_eq!(reparsed.items.len(), file.items.len());
+        for (a, b) in file.items.iter().zip(&reparsed.items) {
+            assert_eq!(std::mem::discriminant(a), std::mem::discriminant(b));
+            assert_eq!(a.name(), b.name());
+            assert_eq!(a.docs(), b.docs());
+        }
+        let Item::Contract(foo) = &reparsed.items[3] else { panic!("{:?}", reparsed.items[3]) };
+        let Item::Contract(orig) = &file.items[3] else { panic!("{:?}", file.items[3]) };
+        assert_eq!(foo.body[0].docs(), orig.body[0].docs());
+        assert_eq!(foo.body[0].docs().len(), 1);
+        assert_eq!(reparsed.to_string(), s);
+
+        // bodies and initializers are not printed
+        let file: File =
+            syn::parse_str("contract C { uint x = 1; function f() { x = 2; } }").unwrap();
+        let s = file.to_string();
+        assert!(s.contains("uint x = <expr>;"), "{s}");
+        assert!(s.contains("function f() { <stmts> }"), "{s}");
+        assert!(syn::parse_str::<File>(&s).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Spanned;
+use crate::{Interner, Spanned};
 use proc_macro2::{Ident, Span};
 use quote::ToTokens;
 use std::fmt;
@@ -17,6 +17,13 @@ pub use path::SolPath;
 /// identifiers for Solidity keywords.
 static KW_DIFFERENCE: &[&str] = &include!("./difference.expr");
 
+/// Solidity keywords and reserved words that can never be used as identifiers.
+///
+/// Contextual keywords, like `from`, `error`, `revert` and `global`, are valid
+/// identifiers and are therefore not included. Solidity reference:
+/// <https://docs.soliditylang.org/en/latest/cheatsheet.html#reserved-keywords>
+static RESERVED: &[&str] = &include!("./reserved.expr");
+
 /// A Solidity identifier.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
@@ -68,6 +75,27 @@ impl From<&str> for SolIdent {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for SolIdent {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.as_string())
+    }
+}
+
+/// Deserializes an identifier from a string, spanned at [`Span::call_site()`].
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SolIdent {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        syn::parse_str::<Self>(&s).map_err(serde::de::Error::custom)
+    }
+}
+
 impl Parse for SolIdent {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         check_dollar(input)?;
@@ -125,6 +153,42 @@ impl SolIdent {
         s
     }
 
+    /// Returns the identifier as a string like [`as_string`](Self::as_string),
+    /// but stored in `interner`, so that equal identifiers share a single
+    /// copy that outlives `self`.
+    ///
+    /// This still allocates a temporary string to look the identifier up, as
+    /// [`Ident`] does not expose its contents as a `&str`.
+    pub fn as_str_in<'a>(&self, interner: &'a mut Interner) -> &'a str {
+        let symbol = interner.intern_ident(self);
+        interner.resolve(symbol)
+    }
+
+    /// Returns `true` if the identifier is a Solidity keyword or reserved word,
+    /// and therefore cannot be used to name a declaration.
+    ///
+    /// This includes all elementary type names, like `uint256`, `bytes32` or
+    /// `fixed128x18`, and the ether and time subdenominations, like `ether`
+    /// and `days`.
+    pub fn is_reserved(&self) -> bool {
+        RESERVED.contains(&self.as_string().as_str())
+            || matches!(crate::Type::parse_ident(self.0.clone()), Ok(ty) if !ty.is_custom())
+    }
+
+    /// Parses an identifier that names a declaration, rejecting reserved
+    /// keywords.
+    pub(crate) fn parse_declaration(input: ParseStream<'_>) -> Result<Self> {
+        let ident: Self = input.parse()?;
+        if ident.is_reserved() {
+            let msg = format!(
+                "`{}` is a reserved keyword and cannot be used as an identifier",
+                ident.as_string()
+            );
+            return Err(syn::Error::new(ident.span(), msg));
+        }
+        Ok(ident)
+    }
+
     /// Parses any identifier including keywords.
     pub fn parse_any(input: ParseStream<'_>) -> Result<Self> {
         check_dollar(input)?;
@@ -187,10 +251,90 @@ mod tests {
         }
     }
 
+    #[test]
+    fn reserved() {
+        use crate::ItemFunction;
+
+        for s in [
+            "function mapping();",
+            "function f(uint payable);",
+            "function f(uint return);",
+            "function uint256();",
+            "function f(uint int8);",
+            "function bytes32();",
+            "function f(uint ufixed128x18);",
+            "function ether();",
+            "function f(uint days);",
+        ] {
+            let e = syn::parse_str::<ItemFunction>(s).unwrap_err();
+            assert!(
+                e.to_string()
+                    .ends_with("is a reserved keyword and cannot be used as an identifier"),
+                "{s}: {e}"
+            );
+        }
+
+        for s in [
+            "function from_();",
+            "function from(address from, uint error);",
+            "function uint7(uint interest, uint bytes33);",
+        ] {
+            syn::parse_str::<ItemFunction>(s).unwrap();
+        }
+    }
+
+    #[test]
+    fn contextual_keywords() {
+        use crate::{File, Item, VariableDeclaration};
+
+        let file: File = syn::parse_str(
+            "
+            function f(uint256 from, address error) returns (bool global);
+            function global();
+            error Foo();
+            using Lib for uint256 global;
+            ",
+        )
+        .unwrap();
+        let [Item::Function(f), Item::Function(global), Item::Error(error), Item::Using(using)] =
+            &file.items[..]
+        else {
+            panic!("{:?}", file.items)
+        };
+        let names = f.parameters.iter().map(|p| p.name.as_ref().unwrap().to_string());
+        assert_eq!(names.collect::<Vec<_>>(), ["from", "error"]);
+        assert_eq!(f.return_params()[0].name.as_ref().unwrap(), "global");
+        assert_eq!(global.name(), "global");
+        assert_eq!(error.name, "Foo");
+        assert!(using.is_global());
+
+        let decl: VariableDeclaration = syn::parse_str("uint256 revert").unwrap();
+        assert_eq!(decl.name.unwrap(), "revert");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let id = SolIdent::new("type");
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, r#""type""#);
+        assert_eq!(serde_json::from_str::<SolIdent>(&json).unwrap(), id);
+        serde_json::from_str::<SolIdent>(r#""a b""#).unwrap_err();
+    }
+
     #[test]
     fn ident_path() {
         let path: SolPath = syn::parse_str("a.b.c").unwrap();
         assert_eq!(path, sol_path!["a", "b", "c"]);
+        assert_eq!(path.first(), "a");
+        assert_eq!(path.last(), "c");
+        assert_eq!(path.to_string(), "a.b.c");
+        assert_eq!(syn::parse_str::<SolPath>(&path.to_string()).unwrap(), path);
+
+        let single: SolPath = syn::parse_str("IERC20").unwrap();
+        assert_eq!(single.len(), 1);
+        assert_eq!(single.first(), single.last());
+        assert_eq!(single.to_string(), "IERC20");
     }
 
     #[test]
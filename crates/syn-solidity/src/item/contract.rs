@@ -1,6 +1,13 @@
-use crate::{kw, utils::DebugPunctuated, Item, Modifier, SolIdent, Spanned, Type};
+use crate::{
+    kw, utils::DebugPunctuated, Expr, FunctionKind, Item, ItemFunction, Modifier, SolIdent,
+    Spanned, Type,
+};
 use proc_macro2::Span;
-use std::{cmp::Ordering, fmt};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt::{self, Write},
+};
 use syn::{
     braced,
     parse::{Lookahead1, Parse, ParseStream},
@@ -26,10 +33,7 @@ pub struct ItemContract {
 
 impl fmt::Display for ItemContract {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", self.kind, self.name)?;
-        if let Some(inheritance) = &self.inheritance {
-            write!(f, " {inheritance}")?;
-        }
+        f.write_str(&self.header())?;
         let s = self
             .body
             .iter()
@@ -105,6 +109,16 @@ impl ItemContract {
         Type::Address(self.span(), None)
     }
 
+    /// Returns the declaration of the contract, without its body:
+    /// `contract C is A, B`.
+    pub(crate) fn header(&self) -> String {
+        let mut s = format!("{} {}", self.kind, self.name);
+        if let Some(inheritance) = &self.inheritance {
+            write!(s, " {inheritance}").unwrap();
+        }
+        s
+    }
+
     /// Returns true if `self` is an abstract contract.
     pub fn is_abstract_contract(&self) -> bool {
         self.kind.is_abstract_contract()
@@ -124,6 +138,184 @@ impl ItemContract {
     pub fn is_library(&self) -> bool {
         self.kind.is_library()
     }
+
+    /// Returns the functions declared in the contract, grouped by name.
+    ///
+    /// Only `function`s are included: constructors, modifiers, and the
+    /// `fallback` and `receive` functions are skipped.
+    pub fn overload_groups(&self) -> HashMap<String, Vec<&ItemFunction>> {
+        let mut groups = HashMap::<_, Vec<_>>::new();
+        for function in self.functions() {
+            if let Some(name) = &function.name {
+                groups.entry(name.as_string()).or_default().push(function);
+            }
+        }
+        groups
+    }
+
+    /// Returns the selectors that are shared by two or more externally
+    /// callable functions with different signatures, in ascending order.
+    ///
+    /// Such functions cannot be told apart by the contract's dispatcher.
+    /// Functions that are not [externally
+    /// callable](ItemFunction::is_externally_callable), and functions whose
+    /// selector cannot be computed, are skipped.
+    pub fn selector_collisions(&self) -> Vec<[u8; 4]> {
+        let mut signatures = BTreeMap::<_, BTreeSet<_>>::new();
+        for function in self.functions() {
+            if function.name.is_none() || !function.is_externally_callable() {
+                continue;
+            }
+            if let Ok(signature) = function.signature() {
+                signatures.entry(crate::utils::selector(&signature)).or_default().insert(signature);
+            }
+        }
+        signatures.into_iter().filter(|(_, s)| s.len() > 1).map(|(selector, _)| selector).collect()
+    }
+
+    fn functions(&self) -> impl Iterator<Item = &ItemFunction> {
+        self.body.iter().filter_map(|item| match item {
+            Item::Function(function) if matches!(function.kind, FunctionKind::Function(_)) => {
+                Some(function)
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the constructor arguments given to the base `name` in the
+    /// inheritance list. See [`Inheritance::base_args`].
+    pub fn base_args(&self, name: &str) -> Option<&Punctuated<Expr, Token![,]>> {
+        self.inheritance.as_ref().and_then(|inheritance| inheritance.base_args(name))
+    }
+
+    /// Returns a best-effort estimate of the storage layout of the contract's
+    /// state variables, in declaration order. `constant` and `immutable`
+    /// variables are not stored, and are skipped.
+    ///
+    /// Variables are assigned sequential slots following Solidity's packing
+    /// rules: value types smaller than 32 bytes share a slot with their
+    /// neighbours when they fit, while structs and arrays always start a new
+    /// slot, and so does the variable after them. Mappings and dynamic
+    /// arrays occupy a single slot.
+    ///
+    /// Custom types are resolved against the enums, structs and user-defined
+    /// value types declared in the contract; types declared elsewhere are
+    /// assumed to occupy a full slot, and so are structs that contain
+    /// themselves, directly or through other structs. Inherited state
+    /// variables are not included. Slot numbers and sizes saturate at
+    /// [`usize::MAX`] for very large fixed-size arrays.
+    pub fn storage_layout(&self) -> Vec<SlotInfo> {
+        let vars = self.body.iter().filter_map(|item| match item {
+            Item::Variable(var)
+                if !var.attributes.has_constant() && !var.attributes.has_immutable() =>
+            {
+                Some((&var.name, &var.ty))
+            }
+            _ => None,
+        });
+        let mut layout = Vec::new();
+        self.layout_into(vars, &mut layout, &mut Vec::new());
+        layout
+    }
+
+    /// Lays out `vars` starting at slot 0, and returns the number of slots
+    /// used.
+    ///
+    /// `resolving` holds the names of the custom types currently being laid
+    /// out, to stop at recursive structs.
+    fn layout_into<'a>(
+        &self,
+        vars: impl IntoIterator<Item = (&'a SolIdent, &'a Type)>,
+        layout: &mut Vec<SlotInfo>,
+        resolving: &mut Vec<String>,
+    ) -> usize {
+        let (mut slot, mut offset) = (0usize, 0);
+        for (name, ty) in vars {
+            let (size, new_slot) = self.storage_size(ty, resolving);
+            if offset > 0 && (new_slot || offset + size > 32) {
+                slot = slot.saturating_add(1);
+                offset = 0;
+            }
+            layout.push(SlotInfo { name: name.clone(), slot, offset, size });
+            if new_slot {
+                slot = slot.saturating_add(size / 32);
+            } else {
+                offset += size;
+            }
+        }
+        slot.saturating_add((offset > 0) as usize)
+    }
+
+    /// Returns the size of `ty` in storage, in bytes, and whether it must
+    /// start a new slot. Types that start a new slot occupy whole slots.
+    fn storage_size(&self, ty: &Type, resolving: &mut Vec<String>) -> (usize, bool) {
+        const SLOT: (usize, bool) = (32, true);
+        match ty {
+            Type::Bool(_) => (1, false),
+            Type::Address(..) => (20, false),
+            Type::FixedBytes(_, size) => (size.get() as usize, false),
+            Type::Int(_, size) | Type::Uint(_, size) => {
+                (size.map_or(32, |size| size.get() as usize / 8), false)
+            }
+            Type::Fixed(_, size) | Type::Ufixed(_, size) => {
+                (size.map_or(16, |(bits, _)| bits.get() as usize / 8), false)
+            }
+            Type::Function(f) => (if f.attributes.has_external() { 24 } else { 8 }, false),
+            Type::String(_) | Type::Bytes(_) | Type::Mapping(_) => SLOT,
+            Type::Array(array) => match array.size_as_usize() {
+                Some(len) => {
+                    let slots = match self.storage_size(&array.ty, resolving) {
+                        (size, true) => len.saturating_mul(size / 32),
+                        (size, false) => {
+                            let per_slot = 32 / size;
+                            len / per_slot + (len % per_slot != 0) as usize
+                        }
+                    };
+                    (slots.saturating_mul(32), true)
+                }
+                None => SLOT,
+            },
+            Type::Tuple(_) => SLOT,
+            Type::Custom(path) => {
+                let name = path.last();
+                let key = name.as_string();
+                if resolving.contains(&key) {
+                    return SLOT;
+                }
+                let item = self.body.iter().find(|item| item.name() == Some(name));
+                resolving.push(key);
+                let size = match item {
+                    Some(Item::Enum(_)) => (1, false),
+                    Some(Item::Contract(_)) => (20, false),
+                    Some(Item::Udt(udt)) => self.storage_size(&udt.ty, resolving),
+                    Some(Item::Struct(strukt)) => {
+                        let fields =
+                            strukt.fields().filter_map(|f| Some((f.name.as_ref()?, &f.ty)));
+                        let slots = self.layout_into(fields, &mut Vec::new(), resolving);
+                        (slots.max(1).saturating_mul(32), true)
+                    }
+                    _ => SLOT,
+                };
+                resolving.pop();
+                size
+            }
+        }
+    }
+}
+
+/// The estimated storage location of a state variable. See
+/// [`ItemContract::storage_layout`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SlotInfo {
+    /// The name of the variable.
+    pub name: SolIdent,
+    /// The first storage slot occupied by the variable.
+    pub slot: usize,
+    /// The offset of the variable in its first slot, in bytes.
+    pub offset: usize,
+    /// The size of the variable in storage, in bytes. This is a multiple of
+    /// 32 for variables that occupy whole slots.
+    pub size: usize,
 }
 
 /// The kind of contract.
@@ -307,10 +499,37 @@ impl Parse for Inheritance {
             inheritance.push_punct(input.parse()?);
         }
         if inheritance.is_empty() {
-            Err(input.parse::<SolIdent>().unwrap_err())
-        } else {
-            Ok(Self { is_token, inheritance })
+            return Err(input.parse::<SolIdent>().unwrap_err());
         }
+        let this = Self { is_token, inheritance };
+        this.check_base_args()?;
+        Ok(this)
+    }
+}
+
+impl Inheritance {
+    /// Returns the constructor arguments given to the base `name`, or `None`
+    /// if it is not in the list or is listed without parentheses.
+    ///
+    /// `name` is compared with the base's full path: `Base` or `Lib.Base`.
+    pub fn base_args(&self, name: &str) -> Option<&Punctuated<Expr, Token![,]>> {
+        self.inheritance
+            .iter()
+            .find(|base| base.paren_token.is_some() && base.name.to_string() == name)
+            .map(|base| &base.arguments)
+    }
+
+    /// Checks that the constructor arguments of each base are given at most
+    /// once.
+    fn check_base_args(&self) -> Result<()> {
+        let with_args = || self.inheritance.iter().filter(|base| base.paren_token.is_some());
+        for (i, base) in with_args().enumerate() {
+            if with_args().take(i).any(|prev| prev.name == base.name) {
+                let msg = format!("base constructor arguments for `{}` given twice", base.name);
+                return Err(Error::new(base.span(), msg));
+            }
+        }
+        Ok(())
     }
 }
 
@@ -324,3 +543,171 @@ impl Spanned for Inheritance {
         self.is_token.span = span;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn overloads() {
+        let contract: ItemContract = parse_quote! {
+            contract C {
+                function transferFrom(address from, address to, uint256 amount) external;
+                function gasprice_bit_ether(int128 x) public;
+                function f(uint256 a) external;
+                function f(uint8 a) external;
+                function f(uint256 a, uint256 b) internal;
+                constructor() {}
+                fallback() external;
+                modifier g(uint256 a) { _; }
+                modifier onlyOwner() { _; }
+            }
+        };
+
+        let groups = contract.overload_groups();
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups["f"].len(), 3);
+        assert_eq!(groups["transferFrom"].len(), 1);
+        let signatures = groups["f"].iter().map(|f| f.signature().unwrap()).collect::<Vec<_>>();
+        assert_eq!(signatures, ["f(uint256)", "f(uint8)", "f(uint256,uint256)"]);
+
+        // a well-known collision: `transferFrom(address,address,uint256)` and
+        // `gasprice_bit_ether(int128)` are both `0x23b872dd`
+        assert_eq!(contract.selector_collisions(), [[0x23, 0xb8, 0x72, 0xdd]]);
+
+        // internal functions are not dispatched
+        let contract: ItemContract = parse_quote! {
+            contract C {
+                function transferFrom(address from, address to, uint256 amount) external;
+                function gasprice_bit_ether(int128 x) internal;
+                function transferFrom(address from, address to, uint256 amount) external;
+            }
+        };
+        assert!(contract.selector_collisions().is_empty());
+    }
+
+    #[test]
+    fn base_args() {
+        let contract: ItemContract =
+            parse_quote!(contract C is Ownable(msg.sender), Pausable, ERC20("Token", "TKN") {});
+        assert_eq!(contract.base_args("Ownable").unwrap().len(), 1);
+        assert!(contract.base_args("Pausable").is_none());
+        assert_eq!(contract.base_args("ERC20").unwrap().len(), 2);
+        assert!(contract.base_args("Missing").is_none());
+
+        let contract: ItemContract = parse_quote!(contract C is A(), Lib.B(1) {});
+        assert!(contract.base_args("A").unwrap().is_empty());
+        assert_eq!(contract.base_args("Lib.B").unwrap().len(), 1);
+
+        let contract: ItemContract = parse_quote!(contract C {});
+        assert!(contract.base_args("A").is_none());
+
+        let e = syn::parse_str::<ItemContract>("contract C is A(1), B, A(2) {}").unwrap_err();
+        assert_eq!(e.to_string(), "base constructor arguments for `A` given twice");
+    }
+
+    #[test]
+    fn inheritance() {
+        let contract: ItemContract = parse_quote! {
+            contract Foo is Bar, IBaz(msg.sender) {
+                uint256 public x;
+                event E();
+                function f() external {}
+            }
+        };
+        assert!(contract.is_contract());
+        let bases = &contract.inheritance.as_ref().unwrap().inheritance;
+        assert_eq!(bases.iter().map(|m| m.name.to_string()).collect::<Vec<_>>(), ["Bar", "IBaz"]);
+        assert!(bases[0].paren_token.is_none());
+        assert_eq!(bases[1].arguments.len(), 1);
+        assert_eq!(contract.body.len(), 3);
+
+        let interface: ItemContract = parse_quote! {
+            interface IERC20 {
+                function totalSupply() external view returns (uint256);
+                function transfer(address to, uint256 amount) external returns (bool);
+            }
+        };
+        assert!(interface.is_interface());
+        assert!(interface.inheritance.is_none());
+        assert!(interface.body.iter().all(|item| matches!(item, Item::Function(_))));
+
+        let e = syn::parse_str::<ItemContract>("library L is A {}").unwrap_err();
+        assert_eq!(e.to_string(), "libraries are not allowed to inherit");
+    }
+
+    #[test]
+    fn storage_layout() {
+        let contract: ItemContract = parse_quote! {
+            contract C {
+                struct Pair { uint8 x; uint16 y; }
+                enum Kind { A, B }
+                type Price is uint64;
+
+                uint128 a;
+                uint128 b;
+                uint256 c;
+                uint256 constant MAX = 1;
+                address immutable owner;
+                bool d;
+                Kind e;
+                Price f;
+                mapping(address => uint) balances;
+                uint8 g;
+                uint64[5] h;
+                Pair i;
+                bytes4 j;
+                string k;
+            }
+        };
+        let layout = contract.storage_layout();
+        let slots = layout
+            .iter()
+            .map(|info| (info.name.to_string(), info.slot, info.offset, info.size))
+            .collect::<Vec<_>>();
+        let expected = [
+            ("a", 0, 0, 16),
+            ("b", 0, 16, 16),
+            ("c", 1, 0, 32),
+            ("d", 2, 0, 1),
+            ("e", 2, 1, 1),
+            ("f", 2, 2, 8),
+            ("balances", 3, 0, 32),
+            ("g", 4, 0, 1),
+            ("h", 5, 0, 64),
+            ("i", 7, 0, 32),
+            ("j", 8, 0, 4),
+            ("k", 9, 0, 32),
+        ];
+        let expected =
+            expected.map(|(name, slot, offset, size)| (name.to_string(), slot, offset, size));
+        assert_eq!(slots, expected);
+
+        // recursive structs are assumed to occupy a full slot
+        let contract: ItemContract = parse_quote! {
+            contract C {
+                struct A { uint8 x; A a; }
+                struct B { C c; }
+                struct C { B b; }
+                A a;
+                B b;
+            }
+        };
+        let sizes =
+            contract.storage_layout().iter().map(|info| (info.slot, info.size)).collect::<Vec<_>>();
+        assert_eq!(sizes, [(0, 64), (2, 32)]);
+
+        // huge arrays saturate instead of overflowing
+        let max = usize::MAX;
+        let contract: ItemContract = syn::parse_str(&format!(
+            "contract C {{ uint256[{max}][2] a; uint8[{max}] b; bool c; }}"
+        ))
+        .unwrap();
+        let layout = contract.storage_layout();
+        assert_eq!(layout[0].size, usize::MAX);
+        assert_eq!(layout[1].size, usize::MAX);
+        assert!(layout[2].slot > layout[1].slot);
+    }
+}
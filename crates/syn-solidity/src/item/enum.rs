@@ -7,7 +7,7 @@ use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     token::Brace,
-    Attribute, Result, Token,
+    Attribute, Error, Result, Token,
 };
 
 /// An enum definition: `enum Foo { A, B, C }`.
@@ -49,13 +49,20 @@ impl fmt::Debug for ItemEnum {
 impl Parse for ItemEnum {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let content;
-        Ok(Self {
+        let this = Self {
             attrs: input.call(Attribute::parse_outer)?,
             enum_token: input.parse()?,
             name: input.parse()?,
             brace_token: braced!(content in input),
             variants: content.parse_terminated(Variant::parse, Token![,])?,
-        })
+        };
+        if this.variants.is_empty() {
+            Err(Error::new(this.span(), "enum has no variants"))
+        } else if this.variants.len() > 256 {
+            Err(Error::new(this.span(), "enum has too many variants"))
+        } else {
+            Ok(this)
+        }
     }
 }
 
@@ -70,9 +77,17 @@ impl Spanned for ItemEnum {
 }
 
 impl ItemEnum {
+    /// Returns the ABI type of the enum: `uint8`.
     pub fn as_type(&self) -> Type {
         Type::Uint(self.span(), Some(NonZeroU16::new(8).unwrap()))
     }
+
+    /// Returns the index of the variant with the given name, if any.
+    ///
+    /// This is the value the variant is ABI-encoded as.
+    pub fn variant_index(&self, name: &str) -> Option<u8> {
+        self.variants.iter().position(|v| v.ident == name).and_then(|i| i.try_into().ok())
+    }
 }
 
 /// An enum variant.
@@ -114,3 +129,28 @@ impl Spanned for Variant {
         self.ident.set_span(span);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn variants() {
+        let status: ItemEnum = parse_quote! {
+            enum Status { Pending, Active, Closed }
+        };
+        assert_eq!(status.as_type().to_string(), "uint8");
+        assert_eq!(status.variant_index("Pending"), Some(0));
+        assert_eq!(status.variant_index("Closed"), Some(2));
+        assert_eq!(status.variant_index("Missing"), None);
+
+        let e = syn::parse_str::<ItemEnum>("enum Empty {}").unwrap_err();
+        assert_eq!(e.to_string(), "enum has no variants");
+
+        let variants = (0..257).map(|i| format!("V{i}")).collect::<Vec<_>>().join(", ");
+        let e = syn::parse_str::<ItemEnum>(&format!("enum Big {{ {variants} }}")).unwrap_err();
+        assert_eq!(e.to_string(), "enum has too many variants");
+    }
+}
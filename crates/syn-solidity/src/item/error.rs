@@ -1,11 +1,11 @@
-use crate::{kw, ParameterList, SolIdent, Spanned, Type};
+use crate::{kw, utils, Mutability, ParameterList, SolIdent, Spanned, Type, Visibility};
 use proc_macro2::Span;
 use std::fmt;
 use syn::{
     parenthesized,
     parse::{Parse, ParseStream},
     token::Paren,
-    Attribute, Result, Token,
+    Attribute, Error, Result, Token,
 };
 
 /// An error definition: `error Foo(uint256 a, uint256 b);`.
@@ -41,14 +41,22 @@ impl fmt::Debug for ItemError {
 impl Parse for ItemError {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let content;
-        Ok(Self {
-            attrs: input.call(Attribute::parse_outer)?,
-            error_token: input.parse()?,
-            name: input.parse()?,
-            paren_token: parenthesized!(content in input),
-            parameters: content.parse()?,
-            semi_token: input.parse()?,
-        })
+        let attrs = input.call(Attribute::parse_outer)?;
+        let error_token = input.parse()?;
+        let name = input.parse()?;
+        let paren_token = parenthesized!(content in input);
+        let parameters = content.parse()?;
+        if let Some(visibility) = input.call(Visibility::parse_opt)? {
+            return Err(Error::new(visibility.span(), "errors cannot have visibility"));
+        }
+        if let Some(mutability) = input.call(Mutability::parse_opt)? {
+            return Err(Error::new(mutability.span(), "errors cannot have state mutability"));
+        }
+        if input.peek(Token![override]) {
+            let override_token: Token![override] = input.parse()?;
+            return Err(Error::new(override_token.span, "errors cannot be declared `override`"));
+        }
+        Ok(Self { attrs, error_token, name, paren_token, parameters, semi_token: input.parse()? })
     }
 }
 
@@ -63,9 +71,74 @@ impl Spanned for ItemError {
 }
 
 impl ItemError {
+    /// Returns the error in the [human-readable ABI] format:
+    /// `error Unauthorized(address caller)`.
+    ///
+    /// [human-readable ABI]: https://docs.ethers.org/v5/api/utils/abi/formats/#abi-formats--human-readable-abi
+    pub fn to_human_readable(&self) -> String {
+        format!("error {}({})", self.name.as_string(), self.parameters.human_readable())
+    }
+
+    /// Returns the error's Solidity signature: `Name(type1,type2,...)`.
+    ///
+    /// The parameter types are in their canonical form, see
+    /// [`Type::canonical_string`].
+    pub fn signature(&self) -> String {
+        self.parameters.signature(self.name.as_string())
+    }
+
+    /// Returns the error's 4-byte selector: the first 4 bytes of the
+    /// Keccak-256 hash of the [signature](Self::signature).
+    pub fn selector(&self) -> [u8; 4] {
+        utils::selector(self.signature())
+    }
+
     pub fn as_type(&self) -> Type {
-        let mut ty = Type::Tuple(self.parameters.types().cloned().collect());
+        let mut ty = Type::Tuple(self.parameters.to_tuple());
         ty.set_span(self.span());
         ty
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn selector() {
+        let error: ItemError = parse_quote! {
+            error Unauthorized();
+        };
+        assert_eq!(error.signature(), "Unauthorized()");
+        assert_eq!(error.selector(), [0x82, 0xb4, 0x29, 0x00]);
+
+        let error: ItemError = parse_quote! {
+            error InsufficientBalance(uint available, uint256 required);
+        };
+        assert_eq!(error.signature(), "InsufficientBalance(uint256,uint256)");
+    }
+
+    #[test]
+    fn attributes() {
+        let e = syn::parse_str::<ItemError>("error Unauthorized() public;").unwrap_err();
+        assert_eq!(e.to_string(), "errors cannot have visibility");
+        let e = syn::parse_str::<ItemError>("error Unauthorized() view;").unwrap_err();
+        assert_eq!(e.to_string(), "errors cannot have state mutability");
+        let e = syn::parse_str::<ItemError>("error Unauthorized() override;").unwrap_err();
+        assert_eq!(e.to_string(), "errors cannot be declared `override`");
+        assert_eq!(e.span().start().column, 21);
+    }
+
+    #[test]
+    fn human_readable() {
+        let error: ItemError = parse_quote!(error InsufficientBalance(uint available, uint256););
+        let s = error.to_human_readable();
+        assert_eq!(s, "error InsufficientBalance(uint256 available, uint256)");
+
+        let reparsed: ItemError = syn::parse_str(&format!("{s};")).unwrap();
+        assert_eq!(reparsed.to_human_readable(), s);
+        assert_eq!(reparsed.parameters, error.parameters);
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{
-    kw, utils::DebugPunctuated, ParameterList, SolIdent, Spanned, Type, VariableDeclaration,
+    kw,
+    utils::{self, DebugPunctuated},
+    ParameterList, SolIdent, Spanned, Type, VariableDeclaration,
 };
 use proc_macro2::Span;
 use std::fmt;
@@ -53,15 +55,27 @@ impl fmt::Debug for ItemEvent {
 impl Parse for ItemEvent {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let content;
-        Ok(Self {
-            attrs: input.call(Attribute::parse_outer)?,
-            event_token: input.parse()?,
-            name: input.parse()?,
-            paren_token: parenthesized!(content in input),
-            parameters: content.parse_terminated(EventParameter::parse, Token![,])?,
-            anonymous: input.parse()?,
+        let attrs = input.call(Attribute::parse_outer)?;
+        let event_token = input.parse()?;
+        let name = input.parse()?;
+        let paren_token = parenthesized!(content in input);
+        let parameters = content.parse_terminated(EventParameter::parse, Token![,])?;
+        let anonymous = input.parse()?;
+        if input.peek(Token![override]) {
+            let override_token: Token![override] = input.parse()?;
+            return Err(Error::new(override_token.span, "events cannot be declared `override`"));
+        }
+        let this = Self {
+            attrs,
+            event_token,
+            name,
+            paren_token,
+            parameters,
+            anonymous,
             semi_token: input.parse()?,
-        })
+        };
+        this.assert_valid()?;
+        Ok(this)
     }
 }
 
@@ -76,6 +90,27 @@ impl Spanned for ItemEvent {
 }
 
 impl ItemEvent {
+    /// Returns the event in the [human-readable ABI] format:
+    /// `event Transfer(address indexed from, address indexed to, uint256 value)`.
+    ///
+    /// [human-readable ABI]: https://docs.ethers.org/v5/api/utils/abi/formats/#abi-formats--human-readable-abi
+    pub fn to_human_readable(&self) -> String {
+        let params = self.parameters.iter().map(|param| {
+            let mut s = param.ty.canonical_string();
+            if param.is_indexed() {
+                s.push_str(" indexed");
+            }
+            if let Some(name) = &param.name {
+                s.push(' ');
+                s.push_str(&name.as_string());
+            }
+            s
+        });
+        let params = params.collect::<Vec<_>>().join(", ");
+        let anonymous = if self.is_anonymous() { " anonymous" } else { "" };
+        format!("event {}({params}){anonymous}", self.name.as_string())
+    }
+
     /// Returns `true` if the event is anonymous.
     #[inline]
     pub const fn is_anonymous(&self) -> bool {
@@ -104,19 +139,45 @@ impl ItemEvent {
     }
 
     /// Asserts that the event has a valid amount of indexed parameters.
+    ///
+    /// The error points at the first `indexed` keyword over the limit.
     pub fn assert_valid(&self) -> Result<()> {
-        if self.exceeds_max_indexed() {
-            let msg = if self.is_anonymous() {
-                "more than 4 indexed arguments for anonymous event"
-            } else {
-                "more than 3 indexed arguments for event"
-            };
-            Err(Error::new(self.span(), msg))
-        } else {
-            Ok(())
+        match self.indexed_params().nth(self.max_indexed()).and_then(|p| p.indexed) {
+            Some(indexed) => {
+                let msg = if self.is_anonymous() {
+                    "more than 4 indexed arguments for anonymous event"
+                } else {
+                    "more than 3 indexed arguments for event"
+                };
+                Err(Error::new(indexed.span, msg))
+            }
+            None => Ok(()),
         }
     }
 
+    /// Returns the event's Solidity signature: `Name(type1,type2,...)`.
+    ///
+    /// The parameter types are in their canonical form, see
+    /// [`Type::canonical_string`]. Whether a parameter is indexed does not
+    /// affect the signature.
+    pub fn signature(&self) -> String {
+        self.params().signature(self.name.as_string())
+    }
+
+    /// Returns the event's selector: the Keccak-256 hash of the
+    /// [signature](Self::signature).
+    ///
+    /// This is emitted as the first topic of non-anonymous events, see
+    /// [`topic0`](Self::topic0).
+    pub fn selector(&self) -> [u8; 32] {
+        utils::keccak256(self.signature())
+    }
+
+    /// Returns the event's first topic, or `None` if the event is anonymous.
+    pub fn topic0(&self) -> Option<[u8; 32]> {
+        (!self.is_anonymous()).then(|| self.selector())
+    }
+
     pub fn params(&self) -> ParameterList {
         self.parameters.iter().map(EventParameter::as_param).collect()
     }
@@ -266,3 +327,119 @@ impl EventParameter {
         self.is_indexed() && self.is_abi_dynamic()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn indexed() {
+        let event: ItemEvent = parse_quote! {
+            event Transfer(address indexed from, address indexed to, uint value);
+        };
+        assert!(!event.is_anonymous());
+        assert_eq!(event.indexed_params().count(), 2);
+        assert_eq!(
+            event.parameters.iter().map(EventParameter::is_indexed).collect::<Vec<_>>(),
+            [true, true, false]
+        );
+        assert_eq!(event.signature(), "Transfer(address,address,uint256)");
+
+        let anonymous: ItemEvent = syn::parse_str(
+            "event A(bool indexed, bool indexed, bool indexed, bool indexed) anonymous;",
+        )
+        .unwrap();
+        assert!(anonymous.is_anonymous());
+
+        let e = syn::parse_str::<ItemEvent>(
+            "event B(bool indexed, bool indexed, bool indexed, bool indexed);",
+        )
+        .unwrap_err();
+        assert_eq!(e.to_string(), "more than 3 indexed arguments for event");
+    }
+
+    #[test]
+    fn no_override() {
+        for s in ["event E() override;", "event E() anonymous override;"] {
+            let e = syn::parse_str::<ItemEvent>(s).unwrap_err();
+            assert_eq!(e.to_string(), "events cannot be declared `override`", "{s}");
+        }
+        let e = syn::parse_str::<ItemEvent>("event E() override;").unwrap_err();
+        assert_eq!(e.span().start().column, 10);
+    }
+
+    #[test]
+    fn indexed_limits() {
+        let ok = [
+            "event A(bool indexed, bool indexed, bool indexed, bool);",
+            "event B(bool indexed, bool indexed, bool indexed, bool indexed) anonymous;",
+        ];
+        for s in ok {
+            syn::parse_str::<ItemEvent>(s).unwrap();
+        }
+
+        let errors = [
+            ("event C(bool indexed, bool indexed, bool, bool indexed, bool indexed);", 61, "3"),
+            (
+                "event D(bool indexed, bool indexed, bool indexed, bool indexed, bool indexed) anonymous;",
+                69,
+                "4",
+            ),
+        ];
+        for (s, column, limit) in errors {
+            let e = syn::parse_str::<ItemEvent>(s).unwrap_err();
+            assert!(e.to_string().starts_with(&format!("more than {limit} indexed")), "{e}");
+            assert_eq!(e.span().start().column, column, "{s}");
+            assert_eq!(&s[column..column + 7], "indexed");
+        }
+    }
+
+    #[test]
+    fn selector() {
+        let event: ItemEvent = parse_quote! {
+            event Transfer(address indexed, address indexed, uint256);
+        };
+        let topic = [
+            0xdd, 0xf2, 0x52, 0xad, 0x1b, 0xe2, 0xc8, 0x9b, 0x69, 0xc2, 0xb0, 0x68, 0xfc, 0x37,
+            0x8d, 0xaa, 0x95, 0x2b, 0xa7, 0xf1, 0x63, 0xc4, 0xa1, 0x16, 0x28, 0xf5, 0x5a, 0x4d,
+            0xf5, 0x23, 0xb3, 0xef,
+        ];
+        assert_eq!(event.selector(), topic);
+        assert_eq!(event.topic0(), Some(topic));
+
+        let unindexed: ItemEvent = parse_quote! {
+            event Transfer(address from, address to, uint value);
+        };
+        assert_eq!(unindexed.selector(), topic);
+
+        let anonymous: ItemEvent = parse_quote! {
+            event Transfer(address indexed, address indexed, uint256) anonymous;
+        };
+        assert_eq!(anonymous.selector(), topic);
+        assert_eq!(anonymous.topic0(), None);
+    }
+
+    #[test]
+    fn human_readable() {
+        for (src, s) in [
+            (
+                "event Transfer(address indexed from, address indexed to, uint value);",
+                "event Transfer(address indexed from, address indexed to, uint256 value)",
+            ),
+            (
+                "event A(string indexed, bytes) anonymous;",
+                "event A(string indexed, bytes) anonymous",
+            ),
+        ] {
+            let event: ItemEvent = syn::parse_str(src).unwrap();
+            assert_eq!(event.to_human_readable(), s);
+
+            let reparsed: ItemEvent = syn::parse_str(&format!("{s};")).unwrap();
+            assert_eq!(reparsed.to_human_readable(), s);
+            assert_eq!(reparsed.parameters, event.parameters);
+            assert_eq!(reparsed.is_anonymous(), event.is_anonymous());
+        }
+    }
+}
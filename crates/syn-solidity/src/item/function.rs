@@ -1,15 +1,17 @@
 use crate::{
-    kw, Block, FunctionAttribute, FunctionAttributes, Mutability, ParameterList, Parameters,
-    SolIdent, Spanned, Stmt, Type, VariableDeclaration, VariableDefinition, Visibility,
+    kw, utils, Block, FunctionAttribute, FunctionAttributes, Mutability, NatSpecTag, ParameterList,
+    Parameters, SolIdent, SolPath, Spanned, StateMutability, Stmt, Type, VariableDeclaration,
+    VariableDefinition, Visibility,
 };
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
 use std::{
-    fmt,
+    fmt::{self, Write},
     hash::{Hash, Hasher},
     num::NonZeroU16,
 };
 use syn::{
-    parenthesized,
+    braced, parenthesized,
     parse::{Parse, ParseStream},
     token::{Brace, Paren},
     Attribute, Error, Result, Token,
@@ -50,7 +52,8 @@ impl fmt::Display for ItemFunction {
             write!(f, " {}", self.attributes)?;
         }
 
-        if let Some(returns) = &self.returns {
+        // an explicitly empty `returns ()` is equivalent to omitting it
+        if let Some(returns) = self.returns.as_ref().filter(|r| !r.returns.is_empty()) {
             write!(f, " {returns}")?;
         }
 
@@ -61,6 +64,30 @@ impl fmt::Display for ItemFunction {
     }
 }
 
+/// Compares the function's [signature](ItemFunction::signature) with the given
+/// one, after canonicalizing its types: `f == "transfer(address, uint)"`.
+///
+/// Always returns `false` if the function has no name.
+impl PartialEq<str> for ItemFunction {
+    fn eq(&self, other: &str) -> bool {
+        if self.name.is_none() {
+            return false;
+        }
+        let canonical = match syn::parse_str::<Self>(&format!("function {other};")) {
+            Ok(other) => other.raw_signature(),
+            Err(_) => other.split_whitespace().collect(),
+        };
+        self.raw_signature() == canonical
+    }
+}
+
+impl PartialEq<&str> for ItemFunction {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
 impl fmt::Debug for ItemFunction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ItemFunction")
@@ -77,9 +104,44 @@ impl fmt::Debug for ItemFunction {
 
 impl Parse for ItemFunction {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
+        Self::parse_with_body(input, FunctionBody::parse, false)
+    }
+}
+
+impl ItemFunction {
+    /// Parses a function like [`Parse::parse`], but without parsing the
+    /// statements of its body, if any. The body tokens are instead kept as-is
+    /// in [`FunctionBody::Raw`].
+    ///
+    /// This is useful to parse implemented functions whose bodies contain
+    /// syntax that is not supported by this crate, or that is not needed.
+    pub fn parse_skip_body(input: ParseStream<'_>) -> Result<Self> {
+        Self::parse_with_body(input, FunctionBody::parse_raw, false)
+    }
+
+    /// Parses a function like [`Parse`] does, but keeps the attributes that
+    /// are not modeled as [unknown](FunctionAttribute::Unknown) attributes.
+    ///
+    /// Functions without an implementation cannot have modifiers, so this
+    /// applies to their other attributes, which would otherwise be parsed as
+    /// modifier invocations. These are rejected by
+    /// [`validate_attributes`](Self::validate_attributes).
+    pub fn parse_tolerant(input: ParseStream<'_>) -> Result<Self> {
+        Self::parse_with_body(input, FunctionBody::parse, true)
+    }
+
+    fn parse_with_body(
+        input: ParseStream<'_>,
+        parse_body: fn(ParseStream<'_>) -> Result<FunctionBody>,
+        tolerant: bool,
+    ) -> Result<Self> {
         let attrs = input.call(Attribute::parse_outer)?;
         let kind: FunctionKind = input.parse()?;
-        let name = input.call(SolIdent::parse_opt)?;
+        let name = if SolIdent::peek_any(input) {
+            Some(input.call(SolIdent::parse_declaration)?)
+        } else {
+            None
+        };
 
         let (paren_token, parameters) = if kind.is_modifier() && !input.peek(Paren) {
             (None, ParameterList::new())
@@ -88,11 +150,21 @@ impl Parse for ItemFunction {
             (Some(parenthesized!(content in input)), content.parse()?)
         };
 
-        let attributes = input.parse()?;
+        let attributes_input = input.fork();
+        let mut attributes: FunctionAttributes = input.parse()?;
         let returns = input.call(Returns::parse_opt)?;
-        let body = input.parse()?;
+        let body = parse_body(input)?;
+        if tolerant && body.is_empty() {
+            attributes.modifiers_to_unknown(&attributes_input)?;
+        }
 
-        Ok(Self { attrs, kind, name, paren_token, parameters, attributes, returns, body })
+        let this = Self { attrs, kind, name, paren_token, parameters, attributes, returns, body };
+        if this.kind.is_receive() {
+            this.check_receive()?;
+        } else if this.kind.is_constructor() {
+            this.check_constructor()?;
+        }
+        Ok(this)
     }
 }
 
@@ -129,6 +201,14 @@ impl ItemFunction {
         }
     }
 
+    /// Returns a builder for a `function` with the given name.
+    ///
+    /// All the tokens are spanned at [`Span::call_site()`]. Names are
+    /// validated when the function is [built](ItemFunctionBuilder::build).
+    pub fn builder(name: &str) -> ItemFunctionBuilder {
+        ItemFunctionBuilder::new(name)
+    }
+
     /// Create a new function with the given name and arguments.
     ///
     /// Note that:
@@ -194,6 +274,22 @@ impl ItemFunction {
         function
     }
 
+    /// Returns the kind of the function, so that it can be matched on once,
+    /// instead of checking the keyword tokens.
+    pub const fn kind(&self) -> FunctionKind {
+        self.kind
+    }
+
+    /// Returns true if the function is a constructor, or a `receive` or
+    /// `fallback` function: a special function that has no name and no
+    /// selector.
+    pub const fn is_constructor_like(&self) -> bool {
+        matches!(
+            self.kind,
+            FunctionKind::Constructor(_) | FunctionKind::Receive(_) | FunctionKind::Fallback(_)
+        )
+    }
+
     /// Returns the name of the function.
     ///
     /// # Panics
@@ -208,6 +304,253 @@ impl ItemFunction {
         }
     }
 
+    /// Renames the function, keeping the span of its current name.
+    ///
+    /// Returns an error if `new_name` is not a valid Solidity identifier, or
+    /// if the function has no name.
+    pub fn rename(&mut self, new_name: &str) -> crate::Result<()> {
+        self.check_rename(new_name).map_err(crate::Error::Validation)
+    }
+
+    fn check_rename(&mut self, new_name: &str) -> Result<()> {
+        let span = self.span();
+        let Some(name) = &mut self.name else {
+            return Err(Error::new(span, format!("`{}` functions have no name", self.kind)));
+        };
+        *name = parse_declaration_name(new_name, span)?;
+        Ok(())
+    }
+
+    /// Returns the span of the entire declaration, from the function kind
+    /// keyword through the terminating semicolon or closing brace.
+    ///
+    /// Falls back to [`span`](Spanned::span) if the spans cannot be joined.
+    pub fn full_span(&self) -> Span {
+        let end = match &self.body {
+            FunctionBody::Empty(semi) => semi.span,
+            FunctionBody::Block(block) => block.brace_token.span.close(),
+            FunctionBody::Raw(brace, _) => brace.span.close(),
+        };
+        self.kind.span().join(end).unwrap_or_else(|| self.span())
+    }
+
+    /// Returns the function's Solidity signature: `name(type1,type2,...)`.
+    ///
+    /// The argument types are in their canonical form, see
+    /// [`Type::canonical_string`].
+    ///
+    /// Returns an error if an argument is not
+    /// [ABI-encodable](Type::is_abi_encodable), or is an array whose size is
+    /// an unevaluated expression, like `uint256[N]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function has no name. See [`name`](Self::name).
+    #[track_caller]
+    pub fn signature(&self) -> crate::Result<String> {
+        check_abi_encodable(&self.parameters).map_err(crate::Error::Validation)?;
+        Ok(self.raw_signature())
+    }
+
+    /// Returns the function's signature without checking that its arguments
+    /// are ABI-encodable.
+    #[track_caller]
+    pub(crate) fn raw_signature(&self) -> String {
+        self.parameters.signature(self.name().as_string())
+    }
+
+    /// Returns the function's signature including its return types:
+    /// `name(type1,type2,...):(ret1,ret2,...)`, with `()` if the function
+    /// returns nothing.
+    ///
+    /// Unlike [`signature`](Self::signature), this is not used to compute the
+    /// selector. Returns an error if an argument or a return type is not
+    /// [ABI-encodable](Type::is_abi_encodable).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function has no name. See [`name`](Self::name).
+    #[track_caller]
+    pub fn full_signature(&self) -> crate::Result<String> {
+        let mut s = self.signature()?;
+        let returns = self.return_params();
+        check_abi_encodable(returns).map_err(crate::Error::Validation)?;
+        s.push(':');
+        Ok(returns.signature(s))
+    }
+
+    /// Returns the function's signature with parameter names, for debugging:
+    /// `transfer(address to, uint256 amount)`.
+    ///
+    /// Types are in their canonical form, and unnamed parameters are named
+    /// after their position: `arg0`, `arg1`, etc. This must not be used to
+    /// compute the selector; see [`signature`](Self::signature) instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function has no name. See [`name`](Self::name).
+    #[track_caller]
+    pub fn signature_with_names(&self) -> String {
+        let mut s = self.name().as_string();
+        s.push('(');
+        for (i, param) in self.parameters.iter().enumerate() {
+            if i > 0 {
+                s.push_str(", ");
+            }
+            param.ty.canonical_string_raw(&mut s);
+            match &param.name {
+                Some(name) => write!(s, " {}", name.as_string()).unwrap(),
+                None => write!(s, " arg{i}").unwrap(),
+            }
+        }
+        s.push(')');
+        s
+    }
+
+    /// Returns the bytes that are hashed to compute the function's
+    /// [selector](Self::selector): the UTF-8 encoding of its
+    /// [signature](Self::signature).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function has no name. See [`name`](Self::name).
+    #[track_caller]
+    pub fn selector_preimage(&self) -> crate::Result<Vec<u8>> {
+        self.signature().map(String::into_bytes)
+    }
+
+    /// Returns the function's 4-byte selector: the first 4 bytes of the
+    /// Keccak-256 hash of the [signature](Self::signature).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function has no name. See [`name`](Self::name).
+    #[track_caller]
+    pub fn selector(&self) -> crate::Result<[u8; 4]> {
+        self.signature().map(utils::selector)
+    }
+
+    /// Returns the function's [selector](Self::selector) formatted as a
+    /// `0x`-prefixed hex string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function has no name. See [`name`](Self::name).
+    #[track_caller]
+    pub fn selector_hex(&self) -> crate::Result<String> {
+        self.selector().map(|selector| utils::hex(&selector))
+    }
+
+    /// Returns the full Keccak-256 hash of the function's
+    /// [signature](Self::signature), formatted as a `0x`-prefixed hex string.
+    ///
+    /// Unlike the 4-byte [selector](Self::selector), which can collide between
+    /// overloads, the full hash is effectively unique, and can be used as a
+    /// stable key for a function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function has no name. See [`name`](Self::name).
+    #[track_caller]
+    pub fn signature_hash_hex(&self) -> crate::Result<String> {
+        self.signature().map(|signature| utils::hex(&utils::keccak256(signature)))
+    }
+
+    /// Returns the function's state mutability, defaulting to
+    /// [`NonPayable`](StateMutability::NonPayable) if unspecified.
+    pub fn state_mutability(&self) -> StateMutability {
+        self.attributes.mutability().into()
+    }
+
+    /// Returns the function's JSON ABI representation:
+    /// `{"type": ..., "name": ..., "inputs": [...], "outputs": [...],
+    /// "stateMutability": ...}`, or `None` for modifiers, which are not part
+    /// of the ABI.
+    ///
+    /// `name` and `outputs` are only present for functions, and `inputs` is
+    /// omitted for `fallback` and `receive` functions. The state mutability
+    /// defaults to `nonpayable` if unspecified.
+    ///
+    /// See [`VariableDeclaration::to_abi_json`] for how parameters are
+    /// represented.
+    #[cfg(feature = "json")]
+    pub fn to_abi_json(&self) -> Option<serde_json::Value> {
+        if self.kind.is_modifier() {
+            return None;
+        }
+        let mut map = serde_json::Map::new();
+        map.insert("type".into(), self.kind.as_str().into());
+        if let Some(name) = &self.name {
+            map.insert("name".into(), name.as_string().into());
+        }
+        if !(self.kind.is_fallback() || self.kind.is_receive()) {
+            map.insert("inputs".into(), self.parameters.to_abi_json().into());
+        }
+        if self.kind.is_function() {
+            map.insert("outputs".into(), self.return_params().to_abi_json().into());
+        }
+        map.insert("stateMutability".into(), self.state_mutability().as_str().into());
+        Some(map.into())
+    }
+
+    /// Returns the function in the [human-readable ABI] format:
+    /// `function transfer(address to, uint256 amount) external returns (bool)`.
+    ///
+    /// Only the visibility and the state mutability attributes are kept, and
+    /// types are in their canonical form without data locations. The output
+    /// can be parsed back into an equivalent function after appending a `;`.
+    ///
+    /// [human-readable ABI]: https://docs.ethers.org/v5/api/utils/abi/formats/#abi-formats--human-readable-abi
+    pub fn to_human_readable(&self) -> String {
+        let mut s = self.kind.as_str().to_string();
+        if let Some(name) = &self.name {
+            s.push(' ');
+            s.push_str(&name.as_string());
+        }
+        s.push('(');
+        s.push_str(&self.parameters.human_readable());
+        s.push(')');
+        if let Some(visibility) = self.attributes.visibility() {
+            s.push(' ');
+            s.push_str(visibility.as_str());
+        }
+        let mutability = self.state_mutability();
+        if mutability != StateMutability::NonPayable {
+            s.push(' ');
+            s.push_str(mutability.as_str());
+        }
+        if !self.is_void() {
+            s.push_str(" returns (");
+            s.push_str(&self.return_params().human_readable());
+            s.push(')');
+        }
+        s
+    }
+
+    /// Compares the ABI of this function with `other`'s, for example an
+    /// older version of the same function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either function has no name. See [`name`](Self::name).
+    #[track_caller]
+    pub fn abi_compatible_with(&self, other: &Self) -> AbiCompat {
+        let (a, b) = (self.state_mutability(), other.state_mutability());
+        AbiCompat {
+            same_selector: self.raw_signature() == other.raw_signature(),
+            same_returns: self.return_params().signature(String::new())
+                == other.return_params().signature(String::new()),
+            mutability_change: (a != b).then_some((a, b)),
+        }
+    }
+
+    /// Returns the function's NatSpec documentation tags.
+    ///
+    /// See [`NatSpecTag::parse_attrs`] for more details.
+    pub fn docs(&self) -> Vec<NatSpecTag> {
+        NatSpecTag::parse_attrs(&self.attrs)
+    }
+
     /// Returns true if the function returns nothing.
     pub fn is_void(&self) -> bool {
         match &self.returns {
@@ -216,19 +559,155 @@ impl ItemFunction {
         }
     }
 
+    /// Returns true if the function looks like the getter of a public state
+    /// variable: a `view` or `pure` function, visible from outside the
+    /// contract (`external` or `public`), that returns at least one value.
+    ///
+    /// This is only a heuristic: getters cannot be told apart from any other
+    /// function with the same shape, and the types of the arguments, which
+    /// are the array indices and mapping keys of a real getter, are not
+    /// checked.
+    pub fn is_getter_like(&self) -> bool {
+        matches!(self.kind, FunctionKind::Function(_))
+            && matches!(self.state_mutability(), StateMutability::View | StateMutability::Pure)
+            && matches!(
+                self.attributes.visibility(),
+                Some(Visibility::External(_) | Visibility::Public(_))
+            )
+            && !self.is_void()
+    }
+
+    /// Returns true if the function can be called from outside the contract:
+    /// it is `external` or `public`, or has no visibility.
+    ///
+    /// Functions without a visibility are considered `public`, which is the
+    /// default in interfaces and in Solidity versions before 0.5.0. Context
+    /// such as free functions, which are always internal, is not taken into
+    /// account. Constructors and modifiers are never externally callable.
+    pub fn is_externally_callable(&self) -> bool {
+        !matches!(self.kind, FunctionKind::Constructor(_) | FunctionKind::Modifier(_))
+            && !matches!(
+                self.attributes.visibility(),
+                Some(Visibility::Internal(_) | Visibility::Private(_))
+            )
+    }
+
+    /// Returns true if the function is declared `payable`, meaning it can
+    /// receive Ether. For constructors, this allows sending Ether along with
+    /// the deployment.
+    pub fn is_payable(&self) -> bool {
+        self.state_mutability() == StateMutability::Payable
+    }
+
     /// Returns true if the function has a body.
     pub fn has_implementation(&self) -> bool {
-        matches!(self.body, FunctionBody::Block(_))
+        matches!(self.body, FunctionBody::Block(_) | FunctionBody::Raw(..))
     }
 
     /// Returns the function's arguments tuple type.
     pub fn call_type(&self) -> Type {
-        Type::Tuple(self.parameters.types().cloned().collect())
+        Type::Tuple(self.parameters.to_tuple())
     }
 
     /// Returns the function's return tuple type.
     pub fn return_type(&self) -> Option<Type> {
-        self.returns.as_ref().map(|returns| Type::Tuple(returns.returns.types().cloned().collect()))
+        self.returns.as_ref().map(|returns| Type::Tuple(returns.returns.to_tuple()))
+    }
+
+    /// Returns the function's arguments and return values tuple types:
+    /// `(call_type, return_type)`.
+    ///
+    /// Unlike [`return_type`](Self::return_type), the returns tuple is empty
+    /// if the function returns nothing.
+    pub fn io_tuple(&self) -> (Type, Type) {
+        (self.call_type(), Type::Tuple(self.return_params().to_tuple()))
+    }
+
+    /// Returns the Rust type of the function's return values: `()` if the
+    /// function returns nothing, the only return type if there is just one,
+    /// or a tuple of the return types otherwise.
+    ///
+    /// Types are given by [`Type::to_rust_type`], and are spelled with fully
+    /// qualified `alloy_sol_types` paths.
+    pub fn return_rust_type(&self) -> TokenStream {
+        let mut types = self.return_types().map(Type::to_rust_type);
+        match types.len() {
+            1 => types.next().unwrap(),
+            _ => quote!((#(#types),*)),
+        }
+    }
+
+    /// Expands the function's arguments into a Rust struct named
+    /// `{name}Call`, with one public field per argument.
+    ///
+    /// Fields are named after their parameter, or `arg{index}` if it is
+    /// unnamed, and their types are given by [`Type::to_rust_type`]. The
+    /// function's doc comments are copied onto the struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function has no name. See [`name`](Self::name).
+    #[track_caller]
+    pub fn expand_call_struct(&self) -> TokenStream {
+        let name = self.name();
+        let call_name = format_ident!("{}Call", name.as_string(), span = name.span());
+        let docs = self.attrs.iter().filter(|attr| attr.path().is_ident("doc"));
+        let fields = self.parameters.iter().enumerate().map(|(i, param)| {
+            let name = match &param.name {
+                Some(name) => quote!(#name),
+                None => format_ident!("arg{i}", span = param.ty.span()).into_token_stream(),
+            };
+            let ty = param.ty.to_rust_type();
+            quote!(pub #name: #ty)
+        });
+        quote! {
+            #(#docs)*
+            #[allow(non_camel_case_types, non_snake_case)]
+            #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+            pub struct #call_name {
+                #(#fields,)*
+            }
+        }
+    }
+
+    /// Returns the function's return parameters.
+    ///
+    /// This is empty if the function returns nothing.
+    pub fn return_params(&self) -> &ParameterList {
+        self.returns.as_ref().map(|returns| &returns.returns).unwrap_or_default()
+    }
+
+    /// Returns an iterator over the function's return types.
+    ///
+    /// This is empty if the function returns nothing.
+    pub fn return_types(
+        &self,
+    ) -> impl ExactSizeIterator<Item = &Type> + DoubleEndedIterator + Clone {
+        self.return_params().types()
+    }
+
+    /// Returns the names of the function's return parameters, in order.
+    ///
+    /// Unnamed return parameters are `None`.
+    pub fn return_names(&self) -> Vec<Option<&SolIdent>> {
+        self.return_params().names().collect()
+    }
+
+    /// Returns the number of values the function returns.
+    pub fn return_count(&self) -> usize {
+        self.return_params().len()
+    }
+
+    /// Returns the paths of all the custom types referenced by the function's
+    /// parameters and return types, deduplicated, in order of appearance.
+    pub fn referenced_custom_types(&self) -> Vec<&SolPath> {
+        let mut paths = Vec::new();
+        for ty in self.parameters.types().chain(self.return_types()) {
+            ty.custom_paths(&mut paths);
+        }
+        let mut seen = std::collections::HashSet::new();
+        paths.retain(|path| seen.insert(path.to_string()));
+        paths
     }
 
     /// Returns a reference to the function's body, if any.
@@ -253,6 +732,108 @@ impl ItemFunction {
             _ => Err(self),
         }
     }
+
+    /// Checks that the function can be declared inside of an interface: it
+    /// must be `external`, and cannot have modifiers or an implementation.
+    pub fn validate_for_interface(&self) -> crate::Result<()> {
+        self.check_interface().map_err(crate::Error::Validation)
+    }
+
+    fn check_interface(&self) -> Result<()> {
+        let visibility = self.attributes.iter().find(|attr| attr.visibility().is_some());
+        if let Some(modifier) = self.attributes.modifier() {
+            Err(Error::new(modifier.span(), "interface functions cannot have modifiers"))
+        } else if self.has_implementation() {
+            Err(Error::new(self.kind.span(), "interface functions cannot have an implementation"))
+        } else {
+            match visibility {
+                Some(attr) if attr.is_external() => Ok(()),
+                Some(attr) => Err(Error::new(
+                    attr.span(),
+                    format!("interface functions must be declared `external`, found `{attr}`"),
+                )),
+                None => Err(Error::new(
+                    self.kind.span(),
+                    "interface functions must be declared `external`",
+                )),
+            }
+        }
+    }
+
+    /// Checks that all of the function's attributes are known.
+    ///
+    /// Since functions without an implementation cannot have modifiers, any
+    /// modifier invocation they have is an unknown attribute. This is the
+    /// strict counterpart of [`parse_tolerant`](Self::parse_tolerant), and
+    /// also rejects the [unknown](FunctionAttribute::Unknown) attributes
+    /// collected by it.
+    pub fn validate_attributes(&self) -> crate::Result<()> {
+        self.check_attributes().map_err(crate::Error::Validation)
+    }
+
+    fn check_attributes(&self) -> Result<()> {
+        for attr in self.attributes.iter() {
+            match attr {
+                FunctionAttribute::Modifier(modifier) if !self.has_implementation() => {
+                    let msg = format!(
+                        "unknown attribute `{}`; functions without implementation cannot have \
+                         modifiers",
+                        modifier.name
+                    );
+                    return Err(Error::new(modifier.span(), msg));
+                }
+                FunctionAttribute::Unknown(tokens) => {
+                    return Err(Error::new(attr.span(), format!("unknown attribute `{tokens}`")));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that a `receive` function has the only signature allowed by
+    /// Solidity: `receive() external payable`.
+    fn check_receive(&self) -> Result<()> {
+        let span = self.kind.span();
+        if !self.parameters.is_empty() {
+            Err(Error::new(self.parameters.span(), "receive functions cannot have parameters"))
+        } else if let Some(returns) = self.returns.as_ref().filter(|_| !self.is_void()) {
+            Err(Error::new(returns.span(), "receive functions cannot return values"))
+        } else if !self.attributes.has_external() {
+            Err(Error::new(span, "receive functions must be declared `external`"))
+        } else if !matches!(self.attributes.mutability(), Some(Mutability::Payable(_))) {
+            Err(Error::new(span, "receive functions must be declared `payable`"))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks that a constructor follows Solidity's constructor grammar: it
+    /// cannot return values, cannot be `virtual`, `override`, `external` or
+    /// `private`, and is either `payable` or has no mutability.
+    fn check_constructor(&self) -> Result<()> {
+        if let Some(returns) = &self.returns {
+            return Err(Error::new(returns.span(), "constructors cannot return values"));
+        }
+        for attr in self.attributes.iter() {
+            let keyword = match attr {
+                FunctionAttribute::Visibility(
+                    visibility @ (Visibility::External(_) | Visibility::Private(_)),
+                ) => visibility.as_str(),
+                FunctionAttribute::Mutability(
+                    mutability @ (Mutability::Pure(_)
+                    | Mutability::View(_)
+                    | Mutability::Constant(_)),
+                ) => mutability.as_str(),
+                FunctionAttribute::Virtual(_) => "virtual",
+                FunctionAttribute::Override(_) => "override",
+                _ => continue,
+            };
+            let msg = format!("constructors cannot be declared `{keyword}`");
+            return Err(Error::new(attr.span(), msg));
+        }
+        Ok(())
+    }
 }
 
 kw_enum! {
@@ -266,12 +847,115 @@ kw_enum! {
     }
 }
 
+/// The result of comparing the ABIs of two functions, created with
+/// [`ItemFunction::abi_compatible_with`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AbiCompat {
+    /// Whether both functions have the same selector, i.e. the same name and
+    /// argument types. Calls to one can be decoded by the other.
+    pub same_selector: bool,
+    /// Whether both functions have the same return types. Names are ignored.
+    pub same_returns: bool,
+    /// The state mutabilities of the first and second function, if they
+    /// differ.
+    pub mutability_change: Option<(StateMutability, StateMutability)>,
+}
+
+impl AbiCompat {
+    /// Returns `true` if both functions have the same selector and return
+    /// types, so that calls and their results can be decoded by either.
+    pub const fn is_compatible(&self) -> bool {
+        self.same_selector && self.same_returns
+    }
+}
+
+/// A builder for [`ItemFunction`], created with [`ItemFunction::builder`].
+///
+/// This allows constructing functions programmatically, without parsing
+/// Solidity source code:
+///
+/// ```
+/// # use proc_macro2::Span;
+/// # use syn_solidity::{ItemFunction, Type, Visibility};
+/// let span = Span::call_site();
+/// let f = ItemFunction::builder("balanceOf")
+///     .arg(Type::Address(span, None), Some("owner"))
+///     .visibility(Visibility::new_external(span))
+///     .returns(Type::Uint(span, None))
+///     .build()?;
+/// assert_eq!(f.to_string(), "function balanceOf(address owner) external returns (uint);");
+/// # Ok::<_, syn_solidity::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct ItemFunctionBuilder {
+    name: String,
+    args: Vec<(Type, Option<String>)>,
+    function: ItemFunction,
+}
+
+impl ItemFunctionBuilder {
+    fn new(name: &str) -> Self {
+        let kind = FunctionKind::new_function(Span::call_site());
+        Self { name: name.to_string(), args: Vec::new(), function: ItemFunction::new(kind, None) }
+    }
+
+    /// Appends a parameter with an optional name.
+    pub fn arg(mut self, ty: Type, name: Option<&str>) -> Self {
+        self.args.push((ty, name.map(str::to_string)));
+        self
+    }
+
+    /// Appends an unnamed return type.
+    pub fn returns(mut self, ty: Type) -> Self {
+        self.function
+            .returns
+            .get_or_insert_with(|| Returns::new(Span::call_site(), ParameterList::new()))
+            .returns
+            .push(VariableDeclaration::new(ty));
+        self
+    }
+
+    /// Sets the visibility, replacing any previously set one.
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.function.attributes.retain(|attr| attr.visibility().is_none());
+        self.function.attributes.push(FunctionAttribute::Visibility(visibility));
+        self
+    }
+
+    /// Sets the state mutability, replacing any previously set one.
+    pub fn mutability(mut self, mutability: Mutability) -> Self {
+        self.function.attributes.retain(|attr| attr.mutability().is_none());
+        self.function.attributes.push(FunctionAttribute::Mutability(mutability));
+        self
+    }
+
+    /// Builds the function.
+    ///
+    /// Returns an error if the name of the function or of one of its
+    /// parameters is not a valid Solidity identifier, or is a reserved
+    /// keyword.
+    pub fn build(self) -> crate::Result<ItemFunction> {
+        self.try_build().map_err(crate::Error::Validation)
+    }
+
+    fn try_build(mut self) -> Result<ItemFunction> {
+        let span = Span::call_site();
+        self.function.name = Some(parse_declaration_name(&self.name, span)?);
+        for (ty, name) in self.args {
+            let name = name.map(|name| parse_declaration_name(&name, span)).transpose()?;
+            self.function.parameters.push(VariableDeclaration::new_with(ty, None, name));
+        }
+        Ok(self.function)
+    }
+}
+
 /// The `returns` attribute of a function.
 #[derive(Clone)]
 pub struct Returns {
     pub returns_token: kw::returns,
     pub paren_token: Paren,
-    /// The returns of the function. This cannot be parsed empty.
+    /// The returns of the function. This may be empty (`returns ()`), in
+    /// which case the enclosing function's `Display` omits the clause.
     pub returns: ParameterList,
 }
 
@@ -306,16 +990,38 @@ impl fmt::Debug for Returns {
 impl Parse for Returns {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let content;
-        let this = Self {
+        Ok(Self {
             returns_token: input.parse()?,
             paren_token: parenthesized!(content in input),
             returns: content.parse()?,
-        };
-        if this.returns.is_empty() {
-            Err(Error::new(this.paren_token.span.join(), "expected at least one return type"))
-        } else {
-            Ok(this)
-        }
+        })
+    }
+}
+
+impl IntoIterator for Returns {
+    type IntoIter = <ParameterList as IntoIterator>::IntoIter;
+    type Item = VariableDeclaration;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.returns.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Returns {
+    type IntoIter = <&'a ParameterList as IntoIterator>::IntoIter;
+    type Item = &'a VariableDeclaration;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.returns.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Returns {
+    type IntoIter = <&'a mut ParameterList as IntoIterator>::IntoIter;
+    type Item = &'a mut VariableDeclaration;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.returns.iter_mut()
     }
 }
 
@@ -352,6 +1058,9 @@ pub enum FunctionBody {
     Empty(Token![;]),
     /// A function body delimited by curly braces.
     Block(Block),
+    /// A function body delimited by curly braces whose statements were not
+    /// parsed. See [`ItemFunction::parse_skip_body`].
+    Raw(Brace, TokenStream),
 }
 
 impl fmt::Display for FunctionBody {
@@ -366,6 +1075,7 @@ impl fmt::Debug for FunctionBody {
         match self {
             Self::Empty(_) => f.write_str("Empty"),
             Self::Block(block) => block.fmt(f),
+            Self::Raw(_, tokens) => f.debug_tuple("Raw").field(tokens).finish(),
         }
     }
 }
@@ -384,6 +1094,25 @@ impl Parse for FunctionBody {
 }
 
 impl FunctionBody {
+    /// Parses a function body, keeping the contents of a block as raw tokens.
+    pub fn parse_raw(input: ParseStream<'_>) -> Result<Self> {
+        if input.peek(Brace) {
+            let content;
+            Ok(Self::Raw(braced!(content in input), content.parse()?))
+        } else {
+            input.parse()
+        }
+    }
+
+    /// Returns the raw tokens of the body, if it was not parsed.
+    #[inline]
+    pub fn raw_tokens(&self) -> Option<&TokenStream> {
+        match self {
+            Self::Raw(_, tokens) => Some(tokens),
+            _ => None,
+        }
+    }
+
     /// Returns `true` if the function body is empty.
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -396,11 +1125,48 @@ impl FunctionBody {
         match self {
             Self::Empty(_) => ";",
             // TODO: fmt::Display for Stmt
-            Self::Block(_) => "{ <stmts> }",
+            Self::Block(_) | Self::Raw(..) => "{ <stmts> }",
         }
     }
 }
 
+/// Parses `name` as the identifier of a declaration, spanned at `span`,
+/// rejecting invalid identifiers and reserved keywords.
+fn parse_declaration_name(name: &str, span: Span) -> Result<SolIdent> {
+    let ident = match syn::parse_str::<SolIdent>(name) {
+        Ok(ident) if name.is_ascii() => ident,
+        _ => {
+            let msg = format!("`{name}` is not a valid Solidity identifier");
+            return Err(Error::new(span, msg));
+        }
+    };
+    if ident.is_reserved() {
+        let msg = format!(
+            "`{}` is a reserved keyword and cannot be used as an identifier",
+            ident.as_string()
+        );
+        return Err(Error::new(span, msg));
+    }
+    Ok(ident.with_span(span))
+}
+
+/// Checks that all of the `params`' types are ABI-encodable.
+fn check_abi_encodable<P>(params: &Parameters<P>) -> Result<()> {
+    for ty in params.types() {
+        if !ty.is_abi_encodable() {
+            return Err(Error::new(
+                ty.span(),
+                format!("`{ty}` cannot be used in an ABI signature"),
+            ));
+        }
+        if ty.has_size_exprs() {
+            let msg = format!("the array size of `{ty}` must be a literal in an ABI signature");
+            return Err(Error::new(ty.span(), msg));
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -412,6 +1178,423 @@ mod tests {
     };
     use syn::parse_quote;
 
+    #[test]
+    fn parameter_comments() {
+        for s in [
+            "function f(uint256 /* amt */ x, address to);",
+            "function f(uint256 /** amt */ x, address /*! recipient */ to);",
+            "function f(uint256 x /* amt */, /* to */ address to /** */);",
+        ] {
+            let f: ItemFunction = syn::parse_str(s).unwrap();
+            assert_eq!(f.signature().unwrap(), "f(uint256,address)", "{s}");
+            assert_eq!(f.to_string(), "function f(uint256 x, address to);", "{s}");
+        }
+    }
+
+    #[test]
+    fn qualified_custom_types() {
+        let f: ItemFunction = parse_quote! {
+            function f(Token.Metadata memory m, Lib.Inner.Kind[] k) external;
+        };
+        let Type::Custom(path) = &f.parameters[0].ty else { panic!("{f:?}") };
+        assert_eq!(path.len(), 2);
+        assert_eq!(path.first(), "Token");
+        assert_eq!(path.last(), "Metadata");
+        assert!(f.parameters[0].storage.is_some());
+
+        let Type::Array(array) = &f.parameters[1].ty else { panic!("{f:?}") };
+        assert!(matches!(&*array.ty, Type::Custom(path) if path.len() == 3));
+
+        assert_eq!(
+            f.to_string(),
+            "function f(Token.Metadata memory m, Lib.Inner.Kind[] k) external;"
+        );
+        assert_eq!(
+            f.referenced_custom_types().iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["Token.Metadata", "Lib.Inner.Kind"]
+        );
+    }
+
+    #[test]
+    fn contract_types() {
+        let f: ItemFunction = parse_quote!(function f(IERC20 token););
+        let Type::Custom(path) = &f.parameters[0].ty else { panic!("{f:?}") };
+        assert_eq!(path.len(), 1);
+        assert_eq!(path.first(), "IERC20");
+        assert_eq!(f.parameters[0].ty.canonical_string(), "IERC20");
+        assert_eq!(f.signature().unwrap(), "f(IERC20)");
+
+        let f: ItemFunction = parse_quote!(function f(Lib.Handle h););
+        let Type::Custom(path) = &f.parameters[0].ty else { panic!("{f:?}") };
+        assert_eq!(path.len(), 2);
+        assert_eq!(f.parameters[0].ty.canonical_string(), "Lib.Handle");
+        assert_eq!(f.signature().unwrap(), "f(Lib.Handle)");
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut f: ItemFunction = parse_quote! {
+            function f(uint a, bool b) returns (string memory, address);
+        };
+
+        let mut types = Vec::new();
+        for param in &f.parameters {
+            types.push(&param.ty);
+        }
+        assert_eq!(types, f.parameters.types().collect::<Vec<_>>());
+
+        let returns = f.returns.as_mut().unwrap();
+        for param in &mut *returns {
+            param.storage = None;
+        }
+        let types = returns.into_iter().map(|param| param.ty.to_string()).collect::<Vec<_>>();
+        assert_eq!(types, ["string", "address"]);
+
+        let names = f.returns.unwrap().into_iter().map(|param| param.name).collect::<Vec<_>>();
+        assert_eq!(names, [None, None]);
+    }
+
+    #[test]
+    fn full_signature() {
+        let transfer: ItemFunction = parse_quote! {
+            function transfer(address to, uint amount) external returns (bool);
+        };
+        assert_eq!(transfer.full_signature().unwrap(), "transfer(address,uint256):(bool)");
+        assert_eq!(transfer.signature().unwrap(), "transfer(address,uint256)");
+
+        let setter: ItemFunction = parse_quote!(function setOwner(address owner) external;);
+        assert_eq!(setter.full_signature().unwrap(), "setOwner(address):()");
+
+        let multi: ItemFunction = parse_quote! {
+            function get() external view returns (uint8[2] memory, (bool, string) memory);
+        };
+        assert_eq!(multi.full_signature().unwrap(), "get():(uint8[2],(bool,string))");
+    }
+
+    #[test]
+    fn getter_like() {
+        for (s, getter) in [
+            ("function balanceOf(address) external view returns (uint256);", true),
+            ("function owner() public view returns (address);", true),
+            ("function decimals() external pure returns (uint8);", true),
+            ("function transfer(address to, uint amount) external returns (bool);", false),
+            ("function deposit() external payable returns (uint256);", false),
+            ("function check() external view;", false),
+            ("function secret() internal view returns (uint256);", false),
+            ("function noVisibility() view returns (uint256);", false),
+        ] {
+            let f: ItemFunction = syn::parse_str(s).unwrap();
+            assert_eq!(f.is_getter_like(), getter, "{s}");
+        }
+    }
+
+    #[test]
+    fn io_tuple() {
+        let f: ItemFunction = parse_quote!(function f(uint256) returns (bool, address););
+        let (args, rets) = f.io_tuple();
+        assert_eq!(args.canonical_string(), "(uint256)");
+        assert_eq!(rets.canonical_string(), "(bool,address)");
+
+        let f: ItemFunction = parse_quote!(function f(););
+        let (args, rets) = f.io_tuple();
+        assert_eq!((args.canonical_string(), rets.canonical_string()), ("()".into(), "()".into()));
+    }
+
+    #[test]
+    fn abi_compat() {
+        let transfer: ItemFunction = parse_quote! {
+            function transfer(address to, uint256 amount) external returns (bool);
+        };
+        let old: ItemFunction = parse_quote!(function transfer(address, uint) external;);
+        let compat = transfer.abi_compatible_with(&old);
+        assert!(compat.same_selector);
+        assert!(!compat.same_returns);
+        assert_eq!(compat.mutability_change, None);
+        assert!(!compat.is_compatible());
+
+        let view: ItemFunction = parse_quote! {
+            function transfer(address, uint256) external view returns (bool success);
+        };
+        let compat = transfer.abi_compatible_with(&view);
+        assert!(compat.is_compatible());
+        assert_eq!(
+            compat.mutability_change,
+            Some((StateMutability::NonPayable, StateMutability::View))
+        );
+
+        let other: ItemFunction = parse_quote!(function transfer(address) external returns (bool););
+        assert!(!transfer.abi_compatible_with(&other).same_selector);
+    }
+
+    #[test]
+    fn human_readable() {
+        for s in [
+            "function transfer(address to, uint256 amount) external returns (bool)",
+            "function balanceOf(address) view returns (uint256 balance)",
+            "function deposit() external payable",
+            "function get((uint256,bool)[2] pairs) public pure returns (string, bytes)",
+            "constructor(address owner)",
+            "receive() external payable",
+        ] {
+            let f: ItemFunction = syn::parse_str(&format!("{s};")).unwrap();
+            let emitted = f.to_human_readable();
+            assert_eq!(emitted, s);
+
+            let reparsed: ItemFunction = syn::parse_str(&format!("{emitted};")).unwrap();
+            assert_eq!(reparsed.kind, f.kind);
+            assert_eq!(reparsed.name, f.name);
+            assert_eq!(reparsed.parameters, f.parameters);
+            assert_eq!(reparsed.attributes, f.attributes);
+            assert_eq!(reparsed.returns, f.returns);
+        }
+
+        let f: ItemFunction = parse_quote! {
+            function f(uint[] memory a) public virtual onlyOwner returns (string memory);
+        };
+        assert_eq!(f.to_human_readable(), "function f(uint256[] a) public returns (string)");
+    }
+
+    #[test]
+    fn selector_preimage() {
+        let f: ItemFunction = parse_quote! {
+            function transfer(address to, uint amount) external returns (bool);
+        };
+        let preimage = f.selector_preimage().unwrap();
+        assert_eq!(String::from_utf8(preimage.clone()).unwrap(), "transfer(address,uint256)");
+        assert_eq!(&crate::utils::keccak256(&preimage)[..4], f.selector().unwrap());
+    }
+
+    #[test]
+    fn returns_edge_cases() {
+        let empty: ItemFunction = parse_quote!(function f() returns (););
+        assert!(empty.returns.is_some());
+        assert!(empty.is_void());
+        assert_eq!(empty.return_count(), 0);
+        assert_eq!(empty.to_string(), "function f();");
+
+        let trailing: ItemFunction = parse_quote!(function f() returns (uint256,););
+        assert!(!trailing.is_void());
+        assert_eq!(trailing.return_count(), 1);
+        assert_eq!(trailing.to_string(), "function f() returns (uint256);");
+
+        let multi: ItemFunction = parse_quote!(function f() returns (uint256 a, bool););
+        assert_eq!(multi.return_count(), 2);
+        assert_eq!(
+            multi.return_types().map(Type::to_string).collect::<Vec<_>>(),
+            ["uint256", "bool"]
+        );
+        assert_eq!(multi.to_string(), "function f() returns (uint256 a, bool);");
+    }
+
+    #[test]
+    fn interface_functions() {
+        let f: ItemFunction = parse_quote!(function f(uint a) external view returns (bool););
+        f.validate_for_interface().unwrap();
+
+        for (f, msg) in [
+            (
+                parse_quote!(function f() public;),
+                "interface functions must be declared `external`, found `public`",
+            ),
+            (parse_quote!(function f();), "interface functions must be declared `external`"),
+            (
+                parse_quote!(function f() external onlyOwner;),
+                "interface functions cannot have modifiers",
+            ),
+            (
+                parse_quote!(function f() external {}),
+                "interface functions cannot have an implementation",
+            ),
+        ] {
+            let f: ItemFunction = f;
+            assert_eq!(f.validate_for_interface().unwrap_err().to_string(), msg, "{f}");
+        }
+    }
+
+    #[test]
+    fn clone_is_deep() {
+        let original: ItemFunction = syn::parse_str("function f(uint a) external;").unwrap();
+        let mut copy = original.clone();
+        copy.name = Some(SolIdent::new("g"));
+        copy.parameters[0].ty = Type::Bool(Span::call_site());
+        copy.set_span(Span::call_site());
+
+        assert_eq!(original.signature().unwrap(), "f(uint256)");
+        assert_eq!(copy.signature().unwrap(), "g(bool)");
+        assert_eq!(original.kind.span().start().column, 0);
+        assert_eq!(original.span().start().column, 9);
+    }
+
+    #[test]
+    fn referenced_custom_types() {
+        let f: ItemFunction = parse_quote! {
+            function f(MyStruct[] calldata a, (uint, Lib.Info) b, MyStruct c) returns (IERC20);
+        };
+        let paths: Vec<_> = f.referenced_custom_types().iter().map(ToString::to_string).collect();
+        assert_eq!(paths, ["MyStruct", "Lib.Info", "IERC20"]);
+    }
+
+    #[test]
+    fn signature_hash() {
+        let a: ItemFunction = parse_quote!(function foo(uint256 x););
+        let b: ItemFunction = parse_quote!(function foo(address x););
+        let (hash_a, hash_b) = (a.signature_hash_hex().unwrap(), b.signature_hash_hex().unwrap());
+        assert_eq!(hash_a.len(), 2 + 64);
+        assert_ne!(hash_a, hash_b);
+        // the selector is the first 4 bytes of the hash
+        assert!(hash_a.starts_with(&a.selector_hex().unwrap()));
+        assert!(hash_b.starts_with(&b.selector_hex().unwrap()));
+    }
+
+    #[test]
+    fn state_mutability() {
+        for (f, mutability) in [
+            (parse_quote!(function f() external view;), StateMutability::View),
+            (parse_quote!(function f() payable external;), StateMutability::Payable),
+            (parse_quote!(function f() external constant;), StateMutability::View),
+            (parse_quote!(function f() external;), StateMutability::NonPayable),
+        ] {
+            let f: ItemFunction = f;
+            assert_eq!(f.state_mutability(), mutability, "{f}");
+        }
+        assert_eq!(StateMutability::default().to_string(), "nonpayable");
+    }
+
+    #[test]
+    fn eq_signature() {
+        let f: ItemFunction = parse_quote!(function transfer(address to, uint amount) external;);
+        assert_eq!(f, "transfer(address,uint256)");
+        assert_eq!(f, "transfer(address, uint)");
+        assert_eq!(f, *" transfer ( address , uint256 ) ");
+        assert_ne!(f, "transferFrom(address,uint256)");
+        assert_ne!(f, "transfer(address)");
+
+        let f: ItemFunction = parse_quote!(fallback() external;);
+        assert_ne!(f, "fallback()");
+    }
+
+    #[test]
+    fn skip_body() {
+        let src = "function f() { return 1; }";
+
+        let parsed: ItemFunction = syn::parse_str(src).unwrap();
+        assert_eq!(parsed.body().map(<[_]>::len), Some(1));
+        assert!(parsed.body.raw_tokens().is_none());
+
+        let skipped = syn::parse::Parser::parse_str(ItemFunction::parse_skip_body, src).unwrap();
+        assert!(skipped.has_implementation());
+        assert!(skipped.body().is_none());
+        assert_eq!(skipped.body.raw_tokens().unwrap().to_string(), "return 1 ;");
+
+        let empty = syn::parse::Parser::parse_str(ItemFunction::parse_skip_body, "function f();");
+        assert!(empty.unwrap().body.is_empty());
+    }
+
+    #[test]
+    fn skip_nested_bodies() {
+        let src = r#"
+            function f() external {
+                unchecked { i++; }
+                assembly { let x := mload(0x40) if iszero(x) { revert(0, 0) } }
+            }
+            function g() external;
+        "#;
+        let parse = |input: ParseStream<'_>| {
+            let mut functions = Vec::new();
+            while !input.is_empty() {
+                functions.push(ItemFunction::parse_skip_body(input)?);
+            }
+            Ok(functions)
+        };
+        let functions = syn::parse::Parser::parse_str(parse, src).unwrap();
+        assert_eq!(functions.len(), 2);
+        let raw = functions[0].body.raw_tokens().unwrap().to_string();
+        assert!(raw.starts_with("unchecked {") && raw.ends_with("} }"), "{raw}");
+        assert_eq!(functions[1].name(), "g");
+        assert!(functions[1].body.is_empty());
+    }
+
+    #[test]
+    fn skip_modifier_bodies() {
+        let src = "
+            modifier m() { require(x); _; }
+            modifier onlyOwner { if (msg.sender != owner) { revert(); } _; emit Called(); }
+            function g() external m onlyOwner;
+        ";
+        let parse = |input: ParseStream<'_>| {
+            let mut functions = Vec::new();
+            while !input.is_empty() {
+                functions.push(ItemFunction::parse_skip_body(input)?);
+            }
+            Ok(functions)
+        };
+        let functions = syn::parse::Parser::parse_str(parse, src).unwrap();
+        assert_eq!(functions.len(), 3);
+        assert!(functions[0].kind.is_modifier());
+        let raw = functions[0].body.raw_tokens().unwrap().to_string();
+        assert_eq!(raw, "require (x) ; _ ;");
+        assert_eq!(functions[1].name(), "onlyOwner");
+        assert!(functions[1].body.raw_tokens().unwrap().to_string().ends_with("emit Called () ;"));
+        assert_eq!(functions[2].name(), "g");
+        assert_eq!(functions[2].attributes.len(), 3);
+
+        // the placeholder is also a valid statement when parsing bodies
+        let file: crate::File = syn::parse_str(src).unwrap();
+        assert_eq!(file.items.len(), 3);
+        let crate::Item::Function(m) = &file.items[0] else { panic!("{:?}", file.items[0]) };
+        assert_eq!(m.body().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn full_span() {
+        let f: ItemFunction = syn::parse_str("function  foo(uint a) external { }").unwrap();
+        let span = f.full_span();
+        assert_eq!(span.start(), f.kind.span().start());
+        assert_ne!(span.start(), f.span().start());
+        assert_eq!((span.start().column, span.end().column), (0, 34));
+
+        let f: ItemFunction = syn::parse_str("function bar();").unwrap();
+        assert_eq!((f.full_span().start().column, f.full_span().end().column), (0, 15));
+    }
+
+    #[test]
+    fn builder() {
+        let span = Span::call_site();
+        let built = ItemFunction::builder("transfer")
+            .arg(Type::Address(span, None), None)
+            .arg(Type::Uint(span, NonZeroU16::new(256)), None)
+            .mutability(Mutability::new_view(span))
+            .mutability(Mutability::new_payable(span))
+            .visibility(Visibility::new_external(span))
+            .returns(Type::Bool(span))
+            .build()
+            .unwrap();
+        let parsed: ItemFunction = parse_quote! {
+            function transfer(address, uint256) external payable returns (bool);
+        };
+        assert_eq!(built.signature().unwrap(), parsed.signature().unwrap());
+        assert_eq!(built.to_string(), parsed.to_string());
+
+        for (name, arg, msg) in [
+            ("1x", None, "`1x` is not a valid Solidity identifier"),
+            ("f", Some("a b"), "`a b` is not a valid Solidity identifier"),
+            (
+                "mapping",
+                None,
+                "`mapping` is a reserved keyword and cannot be used as an identifier",
+            ),
+            (
+                "f",
+                Some("uint256"),
+                "`uint256` is a reserved keyword and cannot be used as an identifier",
+            ),
+        ] {
+            let e = ItemFunction::builder(name).arg(Type::Bool(span), arg).build().unwrap_err();
+            assert!(matches!(e, crate::Error::Validation(_)), "{e:?}");
+            assert_eq!(e.to_string(), msg);
+        }
+    }
+
     #[test]
     fn modifiers() {
         let none: ItemFunction = parse_quote! {
@@ -430,6 +1613,546 @@ mod tests {
         assert_eq!(some.paren_token, Some(Default::default()));
     }
 
+    #[test]
+    fn modifier_definitions() {
+        let decl: ItemFunction = parse_quote!(modifier onlyOwner virtual;);
+        assert!(decl.kind.is_modifier());
+        assert_eq!(decl.name(), "onlyOwner");
+        assert!(decl.parameters.is_empty());
+        assert!(decl.attributes.has_virtual());
+        assert!(!decl.has_implementation());
+
+        let src = "modifier atLeast(uint256 x) override(Base) { require(msg.value >= x); _; }";
+        let def: ItemFunction = syn::parse_str(src).unwrap();
+        assert!(def.kind.is_modifier());
+        assert_eq!(def.parameters.len(), 1);
+        assert_eq!(def.parameters[0].name.as_ref().unwrap(), "x");
+        assert!(def.attributes.has_override(None));
+        assert_eq!(def.body().map(<[_]>::len), Some(2));
+
+        let skipped = syn::parse::Parser::parse_str(ItemFunction::parse_skip_body, src).unwrap();
+        assert!(skipped.kind.is_modifier());
+        assert!(skipped.body.raw_tokens().is_some());
+    }
+
+    #[test]
+    fn modifier_invocations() {
+        let f: ItemFunction = parse_quote! {
+            function mint(address to) public virtual onlyOwner Ownable(msg.sender, 1) override(A) {}
+        };
+        assert!(f.attributes.has_public());
+        assert!(f.attributes.has_virtual());
+        assert_eq!(f.attributes.r#override().unwrap().paths.len(), 1);
+
+        let modifiers = f.attributes.modifiers().collect::<Vec<_>>();
+        assert_eq!(modifiers.len(), 2);
+        assert_eq!(modifiers[0].name.to_string(), "onlyOwner");
+        assert_eq!(modifiers[0].paren_token, None);
+        assert_eq!(modifiers[1].name.to_string(), "Ownable");
+        assert!(modifiers[1].paren_token.is_some());
+        assert_eq!(modifiers[1].arguments.len(), 2);
+    }
+
+    #[test]
+    fn overrides() {
+        for (s, paths) in [
+            ("function f() public override;", &[][..]),
+            ("function f() public override(A);", &["A"][..]),
+            ("function f() public override(A, B.C);", &["A", "B.C"][..]),
+        ] {
+            let f: ItemFunction = syn::parse_str(s).unwrap();
+            let o = f.attributes.r#override().unwrap();
+            assert_eq!(o.paths.iter().map(ToString::to_string).collect::<Vec<_>>(), paths);
+            assert_eq!(f.to_string(), s);
+        }
+
+        let e = syn::parse_str::<ItemFunction>("function f() override();").unwrap_err();
+        assert_eq!(e.to_string(), "expected at least one base");
+    }
+
+    #[test]
+    fn constructors() {
+        let empty: ItemFunction = parse_quote! {
+            constructor() {}
+        };
+        assert!(empty.kind.is_constructor());
+        assert_eq!(empty.name, None);
+        assert!(empty.parameters.is_empty());
+        assert!(empty.attributes.is_empty());
+
+        let with_modifier: ItemFunction = parse_quote! {
+            constructor(uint256 initial) public Ownable(msg.sender) {}
+        };
+        assert!(with_modifier.kind.is_constructor());
+        assert_eq!(with_modifier.parameters.len(), 1);
+        assert!(with_modifier.attributes.has_public());
+        let modifier = with_modifier.attributes.modifier().unwrap();
+        assert_eq!(modifier.name.to_string(), "Ownable");
+        assert_eq!(modifier.arguments.len(), 1);
+        assert!(!with_modifier.is_payable());
+
+        let payable: ItemFunction = parse_quote! {
+            constructor() payable {}
+        };
+        assert!(payable.is_payable());
+        assert_eq!(payable.state_mutability(), StateMutability::Payable);
+
+        for (s, msg) in [
+            ("constructor() view {}", "constructors cannot be declared `view`"),
+            ("constructor() public pure {}", "constructors cannot be declared `pure`"),
+            ("constructor() constant {}", "constructors cannot be declared `constant`"),
+            ("constructor() external {}", "constructors cannot be declared `external`"),
+            ("constructor() private {}", "constructors cannot be declared `private`"),
+            ("constructor() virtual {}", "constructors cannot be declared `virtual`"),
+            ("constructor() public override(A) {}", "constructors cannot be declared `override`"),
+            ("constructor() returns (uint) {}", "constructors cannot return values"),
+        ] {
+            let e = syn::parse_str::<ItemFunction>(s).unwrap_err();
+            assert_eq!(e.to_string(), msg, "{s:?}");
+        }
+        let e = syn::parse_str::<ItemFunction>("constructor() view {}").unwrap_err();
+        assert_eq!(e.span().start().column, 14);
+
+        let internal: ItemFunction = parse_quote!(constructor() internal {});
+        assert!(internal.attributes.has_internal());
+    }
+
+    #[test]
+    fn kinds() {
+        for (s, kind, constructor_like) in [
+            ("function f();", "function", false),
+            ("constructor() {}", "constructor", true),
+            ("receive() external payable {}", "receive", true),
+            ("fallback() external {}", "fallback", true),
+            ("modifier onlyOwner() { _; }", "modifier", false),
+        ] {
+            let f: ItemFunction = syn::parse_str(s).unwrap();
+            assert_eq!(f.kind().as_str(), kind, "{s}");
+            assert_eq!(f.is_constructor_like(), constructor_like, "{s}");
+            assert_eq!(f.name.is_none(), constructor_like, "{s}");
+        }
+
+        let f: ItemFunction = syn::parse_str("receive() external payable {}").unwrap();
+        assert!(matches!(f.kind(), FunctionKind::Receive(_)));
+    }
+
+    #[test]
+    fn unknown_attributes() {
+        let parse_tolerant = |s| syn::parse::Parser::parse_str(ItemFunction::parse_tolerant, s);
+
+        let f = parse_tolerant("function f() somefuture;").unwrap();
+        let unknown = f.attributes.unknown().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(unknown, ["somefuture"]);
+        assert_eq!(f.attributes.modifiers().count(), 0);
+        assert_eq!(f.to_string(), "function f() somefuture;");
+        let e = f.validate_attributes().unwrap_err();
+        assert_eq!(e.to_string(), "unknown attribute `somefuture`");
+
+        let f = parse_tolerant("function f() external somefuture(1) view;").unwrap();
+        assert_eq!(f.attributes.unknown().count(), 1);
+        assert!(f.attributes.has_external());
+        assert_eq!(f.state_mutability(), StateMutability::View);
+        assert_eq!(f.to_string(), "function f() external view somefuture (1);");
+
+        // modifiers of functions with an implementation are kept
+        let f = parse_tolerant("function f() onlyOwner {}").unwrap();
+        assert_eq!(f.attributes.unknown().count(), 0);
+        assert_eq!(f.attributes.modifier().unwrap().name.to_string(), "onlyOwner");
+        f.validate_attributes().unwrap();
+
+        // strict
+        let f: ItemFunction = syn::parse_str("function f() somefuture;").unwrap();
+        assert!(f.attributes.has_modifier(None));
+        let e = f.validate_attributes().unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "unknown attribute `somefuture`; functions without implementation cannot have \
+             modifiers"
+        );
+        assert_eq!(e.span().start().column, 13);
+        let f: ItemFunction = syn::parse_str("function f() external view;").unwrap();
+        f.validate_attributes().unwrap();
+    }
+
+    #[test]
+    fn receive_and_fallback() {
+        let receive: ItemFunction = parse_quote! {
+            receive() external payable virtual {}
+        };
+        assert!(receive.kind.is_receive());
+
+        for (s, msg) in [
+            ("receive() external {}", "must be declared `payable`"),
+            ("receive() public payable {}", "must be declared `external`"),
+            ("receive(uint256 x) external payable {}", "cannot have parameters"),
+            ("receive() external payable returns (bool) {}", "cannot return values"),
+        ] {
+            let e = syn::parse_str::<ItemFunction>(s).unwrap_err();
+            assert!(e.to_string().contains(msg), "{s:?}: {e}");
+        }
+        let empty_returns: ItemFunction = parse_quote!(receive() external payable returns () {});
+        assert!(empty_returns.is_void());
+
+        let legacy: ItemFunction = parse_quote! {
+            fallback() external {}
+        };
+        assert!(legacy.kind.is_fallback());
+        assert!(legacy.parameters.is_empty());
+        assert!(legacy.returns.is_none());
+
+        let new: ItemFunction = parse_quote! {
+            fallback(bytes calldata input) external payable returns (bytes memory output);
+        };
+        assert!(new.kind.is_fallback());
+        assert_eq!(new.parameters.len(), 1);
+        assert_eq!(new.return_type().unwrap().to_string(), "(bytes,)");
+    }
+
+    #[test]
+    fn return_rust_type() {
+        for (f, ty) in [
+            (parse_quote!(function f() returns (uint256);), "U256"),
+            (parse_quote!(function f();), "()"),
+            (parse_quote!(function f() returns (address, bool);), "(Address , bool)"),
+            (parse_quote!(function f() returns (uint8[] memory);), "Vec < u8 >"),
+        ] {
+            let f: ItemFunction = f;
+            let tokens = f.return_rust_type().to_string();
+            assert_eq!(tokens.replace(":: alloy_sol_types :: private :: ", ""), ty, "{f}");
+        }
+    }
+
+    #[test]
+    fn return_types() {
+        let f: ItemFunction = parse_quote! {
+            function f() returns (uint256, bool);
+        };
+        assert_eq!(f.return_count(), 2);
+        let types: Vec<_> = f.return_types().map(ToString::to_string).collect();
+        assert_eq!(types, ["uint256", "bool"]);
+
+        let f: ItemFunction = parse_quote! {
+            function f();
+        };
+        assert_eq!(f.return_count(), 0);
+        assert_eq!(f.return_types().next(), None);
+    }
+
+    #[test]
+    fn return_names() {
+        let f: ItemFunction = parse_quote! {
+            function f() returns (uint256 total, bool);
+        };
+        let names = f.return_names();
+        assert_eq!(names.len(), 2);
+        assert_eq!(names[0].unwrap(), "total");
+        assert_eq!(names[1], None);
+    }
+
+    #[test]
+    fn duplicate_attributes() {
+        for (s, msg) in [
+            ("function f() public public;", "duplicate attribute"),
+            ("function f() view view;", "duplicate attribute"),
+            ("function f() virtual virtual;", "duplicate attribute"),
+            ("function f() override override(A);", "duplicate attribute"),
+            ("function f() public private;", "conflicting visibility attributes"),
+        ] {
+            let e = syn::parse_str::<ItemFunction>(s).unwrap_err();
+            assert!(e.to_string().contains(msg), "{s:?}: {e}");
+        }
+    }
+
+    #[test]
+    fn mutability_conflicts() {
+        for (s, msg) in [
+            ("function f() view payable;", "a function cannot be both `payable` and `view`"),
+            ("function f() payable pure;", "a function cannot be both `payable` and `pure`"),
+            ("function f() constant view;", "a function cannot be both `constant` and `view`"),
+            ("function f() view pure;", "a function cannot be both `view` and `pure`"),
+        ] {
+            let e = syn::parse_str::<ItemFunction>(s).unwrap_err();
+            assert_eq!(e.to_string(), msg, "{s:?}");
+        }
+
+        let f: ItemFunction = parse_quote!(function f() external view;);
+        assert_eq!(f.attributes.mutability(), Some(Mutability::View(Default::default())));
+    }
+
+    #[test]
+    fn constant_mutability() {
+        let f: ItemFunction = parse_quote!(function f() constant returns (uint););
+        assert!(matches!(f.attributes.mutability(), Some(Mutability::Constant(_))));
+        assert_eq!(f.state_mutability(), StateMutability::View);
+        let warnings = f.attributes.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].1, "`constant` is deprecated for functions, use `view` instead");
+
+        let f: ItemFunction = parse_quote!(function f() view returns (uint););
+        assert!(f.attributes.warnings().is_empty());
+    }
+
+    #[test]
+    fn selector() {
+        let f: ItemFunction = parse_quote! {
+            function transfer(address, uint256);
+        };
+        assert_eq!(f.signature().unwrap(), "transfer(address,uint256)");
+        assert_eq!(f.selector().unwrap(), [0xa9, 0x05, 0x9c, 0xbb]);
+        assert_eq!(f.selector_hex().unwrap(), "0xa9059cbb");
+
+        let alias: ItemFunction = parse_quote! {
+            function transfer(address to, uint amount) external returns (bool);
+        };
+        assert_eq!(alias.signature().unwrap(), "transfer(address,uint256)");
+        assert_eq!(alias.selector().unwrap(), f.selector().unwrap());
+    }
+
+    #[test]
+    fn canonical_signature() {
+        let f: ItemFunction = parse_quote! {
+            function f(uint x, int[] y, byte z);
+        };
+        assert_eq!(f.signature().unwrap(), "f(uint256,int256[],bytes1)");
+
+        let f: ItemFunction = parse_quote! {
+            function f((uint, (int[2],))[] x, uint8 y);
+        };
+        assert_eq!(f.signature().unwrap(), "f((uint256,(int256[2]))[],uint8)");
+    }
+
+    #[test]
+    fn data_locations() {
+        let f: ItemFunction = parse_quote! {
+            function f(string memory s, bytes calldata b, uint[] storage c) returns (bytes memory);
+        };
+        let locations = f.parameters.iter().map(|p| p.storage.unwrap().to_string());
+        assert_eq!(locations.collect::<Vec<_>>(), ["memory", "calldata", "storage"]);
+        assert_eq!(f.signature().unwrap(), "f(string,bytes,uint256[])");
+        assert_eq!(
+            f.to_string(),
+            "function f(string memory s, bytes calldata b, uint[] storage c) returns (bytes memory);"
+        );
+    }
+
+    #[test]
+    fn natspec() {
+        let f: ItemFunction = parse_quote! {
+            /// @notice Transfers tokens
+            /// @param to recipient
+            function transfer(address to) external;
+        };
+        let docs = f.docs();
+        assert_eq!(docs.len(), 2);
+        assert_eq!((docs[0].tag.as_str(), docs[0].name.as_deref()), ("notice", None));
+        assert_eq!(docs[0].content, "Transfers tokens");
+        assert_eq!((docs[1].tag.as_str(), docs[1].name.as_deref()), ("param", Some("to")));
+        assert_eq!(docs[1].content, "recipient");
+    }
+
+    #[test]
+    fn display() {
+        let f: ItemFunction = parse_quote! {
+            function f(uint a, bytes memory b) onlyOwner override virtual view external returns (bool);
+        };
+        let s = f.to_string();
+        assert_eq!(
+            s,
+            "function f(uint a, bytes memory b) external view virtual override onlyOwner returns (bool);"
+        );
+
+        let reparsed: ItemFunction = syn::parse_str(&s).unwrap();
+        assert_eq!(reparsed.signature().unwrap(), f.signature().unwrap());
+        assert_eq!(reparsed.to_string(), s);
+    }
+
+    #[test]
+    fn abi_encodable_signature() {
+        let f: ItemFunction = parse_quote!(function f(uint a, mapping(uint => uint) storage m););
+        assert!(!f.parameters[1].ty.is_abi_encodable());
+        let e = f.signature().unwrap_err();
+        assert!(matches!(e, crate::Error::Validation(_)), "{e:?}");
+        assert_eq!(e.to_string(), "`mapping(uint => uint)` cannot be used in an ABI signature");
+        f.selector().unwrap_err();
+        f.selector_hex().unwrap_err();
+
+        let f: ItemFunction = parse_quote!(function f((bool, function () internal)[] a););
+        f.signature().unwrap_err();
+
+        let f: ItemFunction = parse_quote!(function f() returns (mapping(uint => uint) storage););
+        assert_eq!(f.signature().unwrap(), "f()");
+        f.full_signature().unwrap_err();
+
+        let f: ItemFunction = parse_quote!(function f(uint a, (bool, string)[2] b, Custom c););
+        assert_eq!(f.signature().unwrap(), "f(uint256,(bool,string)[2],Custom)");
+        f.selector().unwrap();
+    }
+
+    #[test]
+    fn signature_with_names() {
+        let f: ItemFunction = parse_quote!(function transfer(address to, uint amount););
+        assert_eq!(f.signature_with_names(), "transfer(address to, uint256 amount)");
+
+        let f: ItemFunction =
+            parse_quote!(function f(uint, bytes memory data, (bool, int) calldata););
+        assert_eq!(f.signature_with_names(), "f(uint256 arg0, bytes data, (bool,int256) arg2)");
+        assert_eq!(f.signature().unwrap(), "f(uint256,bytes,(bool,int256))");
+
+        let f: ItemFunction = parse_quote!(function f(););
+        assert_eq!(f.signature_with_names(), "f()");
+    }
+
+    #[test]
+    fn externally_callable() {
+        for (s, callable) in [
+            ("function f() external;", true),
+            ("function f() public;", true),
+            ("function f() internal;", false),
+            ("function f() private;", false),
+            ("function f();", true),
+            ("fallback() external;", true),
+            ("receive() external payable;", true),
+            ("constructor() public {}", false),
+            ("modifier m() { _; }", false),
+        ] {
+            let f: ItemFunction = syn::parse_str(s).unwrap();
+            assert_eq!(f.is_externally_callable(), callable, "{s}");
+        }
+    }
+
+    #[test]
+    fn rename() {
+        let span = Span::call_site();
+        let mut f =
+            ItemFunction::new(FunctionKind::new_function(span), Some(SolIdent::new("type")));
+        f.parameters.push(VariableDeclaration::new(Type::Bool(span)));
+        assert_eq!(f.signature().unwrap(), "type(bool)");
+        f.rename("type_").unwrap();
+        assert_eq!(f.name(), "type_");
+        assert_eq!(f.signature().unwrap(), "type_(bool)");
+
+        let mut f: ItemFunction = syn::parse_str("function transfer(address to);").unwrap();
+        let span = f.span();
+        f.rename("send").unwrap();
+        assert_eq!(f.signature().unwrap(), "send(address)");
+        assert_eq!(f.name().span().start(), span.start());
+        assert_eq!(f.name().span().end(), span.end());
+
+        for (name, msg) in [
+            ("1abc", "`1abc` is not a valid Solidity identifier"),
+            ("a b", "`a b` is not a valid Solidity identifier"),
+            ("", "`` is not a valid Solidity identifier"),
+            ("mapping", "`mapping` is a reserved keyword and cannot be used as an identifier"),
+        ] {
+            let e = f.rename(name).unwrap_err();
+            assert!(matches!(e, crate::Error::Validation(_)), "{e:?}");
+            assert_eq!(e.to_string(), msg);
+        }
+        assert_eq!(f.name(), "send");
+
+        let mut fallback: ItemFunction = parse_quote!(fallback() external;);
+        let e = fallback.rename("f").unwrap_err();
+        assert_eq!(e.to_string(), "`fallback` functions have no name");
+    }
+
+    #[test]
+    fn call_struct() {
+        let f: ItemFunction = parse_quote! {
+            /// Transfers tokens.
+            function transfer(address to, uint256 amount) external returns (bool);
+        };
+        let input: syn::DeriveInput = syn::parse2(f.expand_call_struct()).unwrap();
+        assert_eq!(input.ident, "transferCall");
+        assert_eq!(input.attrs.len(), 3);
+        let syn::Data::Struct(data) = &input.data else { panic!("{input:?}") };
+        let fields = data.fields.iter().map(|field| {
+            format!("{}: {}", field.ident.as_ref().unwrap(), field.ty.to_token_stream())
+        });
+        assert_eq!(
+            fields.collect::<Vec<_>>(),
+            [
+                "to: :: alloy_sol_types :: private :: Address",
+                "amount: :: alloy_sol_types :: private :: U256"
+            ]
+        );
+
+        let f: ItemFunction = parse_quote!(function f(bool, uint8[] memory, string mod););
+        let input: syn::DeriveInput = syn::parse2(f.expand_call_struct()).unwrap();
+        let syn::Data::Struct(data) = &input.data else { panic!("{input:?}") };
+        let names = data.fields.iter().map(|field| field.ident.as_ref().unwrap().to_string());
+        assert_eq!(names.collect::<Vec<_>>(), ["arg0", "arg1", "r#mod"]);
+    }
+
+    #[test]
+    fn attribute_permutations() {
+        let permutations = [
+            "external payable virtual override onlyOwner whenNotPaused",
+            "payable external onlyOwner virtual whenNotPaused override",
+            "onlyOwner whenNotPaused override virtual payable external",
+            "virtual onlyOwner external override whenNotPaused payable",
+            "onlyOwner payable whenNotPaused external override virtual",
+        ];
+        for attrs in permutations {
+            let f: ItemFunction = syn::parse_str(&format!("function f() {attrs};")).unwrap();
+            assert_eq!(
+                f.attributes.to_string(),
+                "external payable virtual override onlyOwner whenNotPaused",
+                "{attrs}"
+            );
+        }
+
+        // modifiers keep their relative order
+        let f: ItemFunction =
+            parse_quote!(function f() whenNotPaused view onlyOwner public nonReentrant;);
+        assert_eq!(f.attributes.to_string(), "public view whenNotPaused onlyOwner nonReentrant");
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn abi_json() {
+        let f: ItemFunction = parse_quote! {
+            function transfer(address to, uint256 amount) external returns (bool);
+        };
+        let expected = serde_json::json!({
+            "type": "function",
+            "name": "transfer",
+            "inputs": [
+                { "name": "to", "type": "address", "internalType": "address" },
+                { "name": "amount", "type": "uint256", "internalType": "uint256" },
+            ],
+            "outputs": [
+                { "name": "", "type": "bool", "internalType": "bool" },
+            ],
+            "stateMutability": "nonpayable",
+        });
+        assert_eq!(f.to_abi_json().unwrap(), expected);
+
+        let f: ItemFunction = parse_quote! {
+            function f((uint, bool)[2] x) view;
+        };
+        let expected = serde_json::json!([{
+            "name": "x",
+            "type": "tuple[2]",
+            "internalType": "tuple[2]",
+            "components": [
+                { "name": "", "type": "uint256", "internalType": "uint256" },
+                { "name": "", "type": "bool", "internalType": "bool" },
+            ],
+        }]);
+        let json = f.to_abi_json().unwrap();
+        assert_eq!(json["inputs"], expected);
+        assert_eq!(json["stateMutability"], "view");
+
+        let f: ItemFunction = parse_quote!(constructor(uint256 x) payable {});
+        let json = f.to_abi_json().unwrap();
+        assert_eq!(json["type"], "constructor");
+        assert_eq!(json["inputs"][0]["type"], "uint256");
+        assert_eq!(json["stateMutability"], "payable");
+
+        let f: ItemFunction = parse_quote!(modifier onlyOwner() { _; });
+        assert_eq!(f.to_abi_json(), None);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore = "takes too long")]
     fn getters() {
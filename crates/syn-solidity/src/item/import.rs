@@ -79,8 +79,10 @@ impl Parse for ImportPath {
             input.parse().map(Self::Glob)
         } else if lookahead.peek(Brace) {
             input.parse().map(Self::Aliases)
-        } else {
+        } else if LitStr::peek(&lookahead) {
             input.parse().map(Self::Plain)
+        } else {
+            Err(lookahead.error())
         }
     }
 }
@@ -323,3 +325,42 @@ impl Spanned for ImportGlob {
         self.path.set_span(span);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[track_caller]
+    fn parse(s: &str) -> ImportDirective {
+        let import: ImportDirective = syn::parse_str(s).unwrap();
+        assert_eq!(import.to_string(), s);
+        import
+    }
+
+    #[test]
+    fn paths() {
+        let import = parse(r#"import "./A.sol";"#);
+        let ImportPath::Plain(plain) = &import.path else { panic!("{import:?}") };
+        assert!(plain.alias.is_none());
+        assert_eq!(import.path.path().value(), "./A.sol");
+
+        let import = parse(r#"import {Foo, Bar as Baz} from "./B.sol";"#);
+        let ImportPath::Aliases(aliases) = &import.path else { panic!("{import:?}") };
+        let imports = aliases
+            .imports
+            .iter()
+            .map(|(ident, alias)| (ident.to_string(), alias.as_ref().map(|a| a.alias.to_string())))
+            .collect::<Vec<_>>();
+        assert_eq!(imports, [("Foo".into(), None), ("Bar".into(), Some("Baz".into()))]);
+        assert_eq!(import.path.path().value(), "./B.sol");
+
+        let import = parse(r#"import * as Lib from "./C.sol";"#);
+        let ImportPath::Glob(glob) = &import.path else { panic!("{import:?}") };
+        assert_eq!(glob.alias.as_ref().unwrap().alias, "Lib");
+        assert_eq!(import.path.path().value(), "./C.sol");
+
+        let e = syn::parse_str::<ImportDirective>("import;").unwrap_err();
+        assert_eq!(e.to_string(), "expected one of: `*`, curly braces, string literal");
+    }
+}
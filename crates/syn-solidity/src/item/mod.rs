@@ -1,5 +1,5 @@
-use crate::{kw, variable::VariableDefinition, SolIdent, Spanned};
-use proc_macro2::Span;
+use crate::{kw, variable::VariableDefinition, NatSpecTag, SolIdent, Spanned};
+use proc_macro2::{Span, TokenStream};
 use std::fmt;
 use syn::{
     parse::{Parse, ParseStream},
@@ -7,7 +7,7 @@ use syn::{
 };
 
 mod contract;
-pub use contract::{ContractKind, Inheritance, ItemContract};
+pub use contract::{ContractKind, Inheritance, ItemContract, SlotInfo};
 
 mod r#enum;
 pub use r#enum::{ItemEnum, Variant};
@@ -19,7 +19,9 @@ mod event;
 pub use event::{EventParameter, ItemEvent};
 
 mod function;
-pub use function::{FunctionBody, FunctionKind, ItemFunction, Returns};
+pub use function::{
+    AbiCompat, FunctionBody, FunctionKind, ItemFunction, ItemFunctionBuilder, Returns,
+};
 
 mod import;
 pub use import::{
@@ -30,7 +32,7 @@ mod pragma;
 pub use pragma::{PragmaDirective, PragmaTokens};
 
 mod r#struct;
-pub use r#struct::ItemStruct;
+pub use r#struct::{ItemStruct, StructRegistry};
 
 mod udt;
 pub use udt::ItemUdt;
@@ -78,6 +80,10 @@ pub enum Item {
 
     /// A state variable or constant definition: `uint256 constant FOO = 42;`
     Variable(VariableDefinition),
+
+    /// Tokens that could not be parsed as an item. Only produced by
+    /// [`File::parse_tolerant`](crate::File::parse_tolerant).
+    Verbatim(TokenStream),
 }
 
 impl fmt::Display for Item {
@@ -94,6 +100,7 @@ impl fmt::Display for Item {
             Self::Udt(item) => item.fmt(f),
             Self::Using(item) => item.fmt(f),
             Self::Variable(item) => item.fmt(f),
+            Self::Verbatim(tokens) => tokens.fmt(f),
         }
     }
 }
@@ -113,6 +120,7 @@ impl fmt::Debug for Item {
             Self::Udt(item) => item.fmt(f),
             Self::Using(item) => item.fmt(f),
             Self::Variable(item) => item.fmt(f),
+            Self::Verbatim(tokens) => f.debug_tuple("Verbatim").field(&tokens.to_string()).finish(),
         }
     }
 }
@@ -169,6 +177,7 @@ impl Spanned for Item {
             Self::Udt(udt) => udt.span(),
             Self::Using(using) => using.span(),
             Self::Variable(variable) => variable.span(),
+            Self::Verbatim(tokens) => tokens.span(),
         }
     }
 
@@ -185,6 +194,7 @@ impl Spanned for Item {
             Self::Udt(udt) => udt.set_span(span),
             Self::Using(using) => using.set_span(span),
             Self::Variable(variable) => variable.set_span(span),
+            Self::Verbatim(tokens) => tokens.set_span(span),
         }
     }
 }
@@ -213,10 +223,17 @@ impl Item {
             | Self::Struct(ItemStruct { attrs, .. })
             | Self::Udt(ItemUdt { attrs, .. })
             | Self::Variable(VariableDefinition { attrs, .. }) => Some(attrs),
-            Self::Import(_) | Self::Pragma(_) | Self::Using(_) => None,
+            Self::Import(_) | Self::Pragma(_) | Self::Using(_) | Self::Verbatim(_) => None,
         }
     }
 
+    /// Returns the item's NatSpec documentation tags.
+    ///
+    /// See [`NatSpecTag::parse_attrs`] for more details.
+    pub fn docs(&self) -> Vec<NatSpecTag> {
+        self.attrs().map(|attrs| NatSpecTag::parse_attrs(attrs)).unwrap_or_default()
+    }
+
     pub fn attrs_mut(&mut self) -> Option<&mut Vec<Attribute>> {
         match self {
             Self::Contract(ItemContract { attrs, .. })
@@ -227,7 +244,7 @@ impl Item {
             | Self::Struct(ItemStruct { attrs, .. })
             | Self::Udt(ItemUdt { attrs, .. })
             | Self::Variable(VariableDefinition { attrs, .. }) => Some(attrs),
-            Self::Import(_) | Self::Pragma(_) | Self::Using(_) => None,
+            Self::Import(_) | Self::Pragma(_) | Self::Using(_) | Self::Verbatim(_) => None,
         }
     }
 
@@ -1,5 +1,5 @@
 use crate::{kw, utils::tts_until_semi, SolIdent, Spanned};
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Spacing, Span, TokenStream, TokenTree};
 use std::fmt;
 use syn::{
     parse::{Parse, ParseStream},
@@ -49,6 +49,61 @@ impl Spanned for PragmaDirective {
     }
 }
 
+impl PragmaDirective {
+    /// Returns the version requirement of a `pragma solidity` directive, e.g.
+    /// `>=0.7.0 <0.9.0`.
+    ///
+    /// The tokens are not validated as a version requirement; they are only
+    /// formatted back into a string, with whitespace removed inside of each
+    /// version comparator.
+    pub fn version_req(&self) -> Option<String> {
+        match &self.tokens {
+            PragmaTokens::Version(_, version) => Some(fmt_version_req(version.clone())),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is a `pragma experimental` directive.
+    pub const fn is_experimental(&self) -> bool {
+        matches!(self.tokens, PragmaTokens::Experimental(..))
+    }
+
+    /// Returns the feature enabled by a `pragma experimental` directive, e.g.
+    /// `ABIEncoderV2` or `SMTChecker`.
+    pub const fn experimental_feature(&self) -> Option<&SolIdent> {
+        match &self.tokens {
+            PragmaTokens::Experimental(_, feature) => Some(feature),
+            _ => None,
+        }
+    }
+}
+
+fn fmt_version_req(tokens: TokenStream) -> String {
+    let mut s = String::new();
+    let mut prev: Option<TokenTree> = None;
+    for tt in tokens {
+        let space = match (&prev, &tt) {
+            (None, _) => false,
+            // `>=`, `||`
+            (Some(TokenTree::Punct(p)), _) if p.spacing() == Spacing::Joint => false,
+            // `0.8` `.` `0`
+            (Some(TokenTree::Punct(p)), _) if p.as_char() == '.' => false,
+            (_, TokenTree::Punct(p)) if p.as_char() == '.' => false,
+            // `^0.8.0`
+            (Some(TokenTree::Punct(p)), TokenTree::Literal(_)) => {
+                !matches!(p.as_char(), '^' | '~' | '<' | '>' | '=')
+            }
+            _ => true,
+        };
+        if space {
+            s.push(' ');
+        }
+        s.push_str(&tt.to_string());
+        prev = Some(tt);
+    }
+    s
+}
+
 #[derive(Clone, Debug)]
 pub enum PragmaTokens {
     Version(kw::solidity, TokenStream),
@@ -124,3 +179,49 @@ impl Spanned for PragmaTokens {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn version_req() {
+        for (s, req) in [
+            ("pragma solidity >=0.7.0 <0.9.0;", ">=0.7.0 <0.9.0"),
+            ("pragma solidity ^0.8.0;", "^0.8.0"),
+            ("pragma solidity 0.8.0 || ^0.7;", "0.8.0 || ^0.7"),
+        ] {
+            let pragma: PragmaDirective = syn::parse_str(s).unwrap();
+            assert_eq!(pragma.version_req().as_deref(), Some(req));
+        }
+
+        let pragma: PragmaDirective = syn::parse_str("pragma abicoder v2;").unwrap();
+        let PragmaTokens::Abicoder(_, version) = &pragma.tokens else { panic!("{pragma:?}") };
+        assert_eq!(version, "v2");
+        assert_eq!(pragma.version_req(), None);
+        assert_eq!(pragma.to_string(), "pragma abicoder v2;");
+    }
+
+    #[test]
+    fn experimental() {
+        for feature in ["ABIEncoderV2", "SMTChecker"] {
+            let s = format!("pragma experimental {feature};");
+            let pragma: PragmaDirective = syn::parse_str(&s).unwrap();
+            assert!(pragma.is_experimental());
+            assert_eq!(pragma.experimental_feature().unwrap(), feature);
+            assert_eq!(pragma.version_req(), None);
+            assert_eq!(pragma.to_string(), s);
+        }
+
+        let pragma: PragmaDirective = syn::parse_str("pragma solidity ^0.8.0;").unwrap();
+        assert!(!pragma.is_experimental());
+        assert_eq!(pragma.experimental_feature(), None);
+
+        let file: crate::File = syn::parse_str(
+            "pragma solidity ^0.6.0; pragma experimental ABIEncoderV2; contract C {}",
+        )
+        .unwrap();
+        assert_eq!(file.items.len(), 3);
+    }
+}
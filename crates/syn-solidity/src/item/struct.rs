@@ -1,6 +1,7 @@
-use crate::{FieldList, SolIdent, Spanned, Type};
+use crate::{FieldList, Item, SolIdent, Spanned, Type, VariableDeclaration};
 use proc_macro2::Span;
 use std::{
+    collections::{BTreeMap, BTreeSet},
     fmt,
     hash::{Hash, Hasher},
 };
@@ -81,9 +82,225 @@ impl Spanned for ItemStruct {
 }
 
 impl ItemStruct {
+    /// Returns an iterator over the struct's fields, in declaration order.
+    pub fn fields(
+        &self,
+    ) -> impl ExactSizeIterator<Item = &VariableDeclaration> + DoubleEndedIterator + Clone {
+        self.fields.iter()
+    }
+
+    /// Returns the ABI tuple representation of the struct, as used in
+    /// selector preimages: `(type1,type2,...)`.
+    ///
+    /// The field types are in their canonical form, see
+    /// [`Type::canonical_string`]. Fields referencing other custom types are
+    /// not resolved, and are formatted by name.
+    pub fn tuple_signature(&self) -> String {
+        self.fields.signature(String::new())
+    }
+
     pub fn as_type(&self) -> Type {
-        let mut ty = Type::Tuple(self.fields.types().cloned().collect());
+        let mut ty = Type::Tuple(self.fields.to_tuple());
         ty.set_span(self.span());
         ty
     }
+
+    /// Returns the struct's [EIP-712 `encodeType`][ref]: its own type string,
+    /// followed by the type strings of all the structs it references,
+    /// directly or transitively, sorted by name.
+    ///
+    /// `deps` is used to resolve the struct types referenced by the fields.
+    /// Custom types that are not in `deps` are formatted by name and are not
+    /// expanded. Elementary types are spelled canonically (`uint256` for
+    /// `uint`), and struct references by their unqualified name (`Person` for
+    /// `Mail.Person`).
+    ///
+    /// [ref]: https://eips.ethereum.org/EIPS/eip-712#definition-of-encodetype
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use syn_solidity::{ItemStruct, StructRegistry};
+    /// let person: ItemStruct = syn::parse_str("struct Person { string name; address wallet; }")?;
+    /// let mail: ItemStruct =
+    ///     syn::parse_str("struct Mail { Person from; Person to; string contents; }")?;
+    /// let deps = StructRegistry::from_iter([person]);
+    /// assert_eq!(
+    ///     mail.eip712_encode_type(&deps),
+    ///     "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+    /// );
+    /// # syn::Result::Ok(())
+    /// ```
+    pub fn eip712_encode_type(&self, deps: &StructRegistry) -> String {
+        let mut names = BTreeSet::new();
+        self.eip712_deps(deps, &mut names);
+        names.remove(self.name.as_string().as_str());
+
+        let mut s = self.fields.eip712_signature(self.name.as_string());
+        for name in names {
+            s.push_str(&deps.structs[name].fields.eip712_signature(name.to_string()));
+        }
+        s
+    }
+
+    /// Collects the names of the structs in `deps` referenced by `self`,
+    /// recursively.
+    fn eip712_deps<'a>(&self, deps: &'a StructRegistry, names: &mut BTreeSet<&'a str>) {
+        let mut paths = Vec::new();
+        for field in self.fields() {
+            field.ty.custom_paths(&mut paths);
+        }
+        for path in paths {
+            if let Some((name, dep)) = deps.structs.get_key_value(&path.last().as_string()) {
+                if names.insert(name) {
+                    dep.eip712_deps(deps, names);
+                }
+            }
+        }
+    }
+}
+
+/// A set of struct definitions, keyed by name.
+///
+/// Used to resolve the structs referenced by other structs in
+/// [`ItemStruct::eip712_encode_type`].
+#[derive(Clone, Debug, Default)]
+pub struct StructRegistry {
+    structs: BTreeMap<String, ItemStruct>,
+}
+
+impl FromIterator<ItemStruct> for StructRegistry {
+    fn from_iter<T: IntoIterator<Item = ItemStruct>>(iter: T) -> Self {
+        let mut registry = Self::new();
+        registry.extend(iter);
+        registry
+    }
+}
+
+impl Extend<ItemStruct> for StructRegistry {
+    fn extend<T: IntoIterator<Item = ItemStruct>>(&mut self, iter: T) {
+        for strukt in iter {
+            self.insert(strukt);
+        }
+    }
+}
+
+impl StructRegistry {
+    /// Creates a new, empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry with the structs of `items`, including those
+    /// nested in contracts.
+    pub fn from_items<'a>(items: impl IntoIterator<Item = &'a Item>) -> Self {
+        let mut registry = Self::new();
+        registry.extend_from_items(items);
+        registry
+    }
+
+    fn extend_from_items<'a>(&mut self, items: impl IntoIterator<Item = &'a Item>) {
+        for item in items {
+            match item {
+                Item::Struct(strukt) => {
+                    self.insert(strukt.clone());
+                }
+                Item::Contract(contract) => self.extend_from_items(&contract.body),
+                _ => {}
+            }
+        }
+    }
+
+    /// Inserts a struct, returning the previous struct with the same name,
+    /// if any.
+    pub fn insert(&mut self, strukt: ItemStruct) -> Option<ItemStruct> {
+        self.structs.insert(strukt.name.as_string(), strukt)
+    }
+
+    /// Returns the struct with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&ItemStruct> {
+        self.structs.get(name)
+    }
+
+    /// Returns the number of structs in the registry.
+    pub fn len(&self) -> usize {
+        self.structs.len()
+    }
+
+    /// Returns `true` if the registry contains no structs.
+    pub fn is_empty(&self) -> bool {
+        self.structs.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn fields() {
+        let point: ItemStruct = parse_quote! {
+            struct Point { uint256 x; uint y; }
+        };
+        assert_eq!(point.name, "Point");
+        let names = point.fields().map(|f| f.name.as_ref().unwrap().to_string());
+        assert_eq!(names.collect::<Vec<_>>(), ["x", "y"]);
+        assert_eq!(point.tuple_signature(), "(uint256,uint256)");
+
+        let nested: ItemStruct = parse_quote! {
+            struct Values { uint256[] values; Point origin; }
+        };
+        assert!(nested.fields().next().unwrap().ty.is_array());
+        assert!(nested.fields().last().unwrap().ty.is_custom());
+        assert_eq!(nested.tuple_signature(), "(uint256[],Point)");
+    }
+
+    #[test]
+    fn eip712_encode_type() {
+        let file: crate::File = syn::parse_str(
+            r#"
+            struct Person { string name; address wallet; }
+            struct Mail { Person from; Person to; string contents; }
+            contract C {
+                struct Z { uint256 x; }
+                struct B { Z z; Mail.Person p; }
+                struct A { Z[] zs; B[2] b; A[] children; }
+            }
+            "#,
+        )
+        .unwrap();
+        let deps = StructRegistry::from_items(&file.items);
+        assert_eq!(deps.len(), 5);
+
+        assert_eq!(
+            deps.get("Mail").unwrap().eip712_encode_type(&deps),
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        );
+        assert_eq!(
+            deps.get("Person").unwrap().eip712_encode_type(&deps),
+            "Person(string name,address wallet)"
+        );
+        assert_eq!(
+            deps.get("A").unwrap().eip712_encode_type(&deps),
+            "A(Z[] zs,B[2] b,A[] children)\
+             B(Z z,Person p)\
+             Person(string name,address wallet)\
+             Z(uint256 x)"
+        );
+
+        let unresolved = deps.get("B").unwrap().eip712_encode_type(&StructRegistry::new());
+        assert_eq!(unresolved, "B(Z z,Person p)");
+
+        let file: crate::File = syn::parse_str(
+            "struct S { uint x; int8 y; int z; byte b; uint[2][] xs; address payable a; }",
+        )
+        .unwrap();
+        let deps = StructRegistry::from_items(&file.items);
+        assert_eq!(
+            deps.get("S").unwrap().eip712_encode_type(&deps),
+            "S(uint256 x,int8 y,int256 z,bytes1 b,uint256[2][] xs,address a)"
+        );
+    }
 }
@@ -66,7 +66,7 @@ impl Parse for ItemUdt {
         };
 
         // Solidity doesn't allow this, and it would cause ambiguity later on
-        if this.ty.has_custom() {
+        if !this.ty.is_value_type() {
             return Err(syn::Error::new(
                 this.ty.span(),
                 "the underlying type for a user-defined value type has to be an elementary value type",
@@ -90,3 +90,20 @@ impl Spanned for ItemUdt {
         self.semi_token.span = span;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn underlying_type() {
+        let udt: ItemUdt = syn::parse_str("type Decimal is int256;").unwrap();
+        assert_eq!(udt.name, "Decimal");
+        assert_eq!(udt.ty.to_string(), "int256");
+
+        for s in ["type Bad is uint256[];", "type Bad is string;", "type Bad is Other;"] {
+            let e = syn::parse_str::<ItemUdt>(s).unwrap_err();
+            assert!(e.to_string().contains("has to be an elementary value type"), "{s:?}: {e}");
+        }
+    }
+}
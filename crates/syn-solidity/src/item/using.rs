@@ -58,6 +58,14 @@ impl Parse for UsingDirective {
     }
 }
 
+impl UsingDirective {
+    /// Returns `true` if the directive is marked `global`.
+    #[inline]
+    pub const fn is_global(&self) -> bool {
+        self.global_token.is_some()
+    }
+}
+
 impl Spanned for UsingDirective {
     fn span(&self) -> Span {
         let span = self.using_token.span;
@@ -229,3 +237,32 @@ op_enum! {
         Ne(!=),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn using_directives() {
+        let single: UsingDirective = parse_quote!(using SafeMath for uint256;);
+        assert!(matches!(&single.list, UsingList::Single(path) if path.to_string() == "SafeMath"));
+        assert!(matches!(&single.ty, UsingType::Type(Type::Uint(_, _))));
+        assert!(!single.is_global());
+        assert_eq!(single.to_string(), "using SafeMath for uint256;");
+
+        let list: UsingDirective = parse_quote!(using { add, Lib.sub as - } for uint256 global;);
+        let UsingList::Multiple(_, items) = &list.list else { panic!("{list:?}") };
+        assert_eq!(items.len(), 2);
+        assert!(items[0].op.is_none());
+        assert_eq!(items[1].path.to_string(), "Lib.sub");
+        assert!(matches!(items[1].op, Some((_, UserDefinableOperator::Sub(_)))));
+        assert!(list.is_global());
+        assert_eq!(list.to_string(), "using {add, Lib.sub as -} for uint256 global;");
+
+        let star: UsingDirective = parse_quote!(using Strings for *;);
+        assert!(matches!(star.ty, UsingType::Star(_)));
+        assert_eq!(star.to_string(), "using Strings for *;");
+    }
+}
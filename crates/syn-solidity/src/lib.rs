@@ -10,17 +10,18 @@
 
 extern crate proc_macro;
 
-use syn::Result;
-
 #[macro_use]
 mod macros;
 
 mod attribute;
 pub use attribute::{
-    FunctionAttribute, FunctionAttributes, Modifier, Mutability, Override, Storage,
-    VariableAttribute, VariableAttributes, Visibility,
+    FunctionAttribute, FunctionAttributes, Modifier, Mutability, Override, StateMutability,
+    Storage, VariableAttribute, VariableAttributes, Visibility,
 };
 
+mod error;
+pub use error::{Error, Result};
+
 mod expr;
 pub use expr::{
     ArgList, ArgListImpl, BinOp, Expr, ExprArray, ExprBinary, ExprCall, ExprCallOptions,
@@ -36,11 +37,11 @@ pub use ident::{SolIdent, SolPath};
 
 mod item;
 pub use item::{
-    ContractKind, EventParameter, FunctionBody, FunctionKind, ImportAlias, ImportAliases,
-    ImportDirective, ImportGlob, ImportPath, ImportPlain, Inheritance, Item, ItemContract,
-    ItemEnum, ItemError, ItemEvent, ItemFunction, ItemStruct, ItemUdt, PragmaDirective,
-    PragmaTokens, Returns, UserDefinableOperator, UsingDirective, UsingList, UsingListItem,
-    UsingType, Variant,
+    AbiCompat, ContractKind, EventParameter, FunctionBody, FunctionKind, ImportAlias,
+    ImportAliases, ImportDirective, ImportGlob, ImportPath, ImportPlain, Inheritance, Item,
+    ItemContract, ItemEnum, ItemError, ItemEvent, ItemFunction, ItemFunctionBuilder, ItemStruct,
+    ItemUdt, PragmaDirective, PragmaTokens, Returns, SlotInfo, StructRegistry,
+    UserDefinableOperator, UsingDirective, UsingList, UsingListItem, UsingType, Variant,
 };
 
 mod lit;
@@ -51,9 +52,18 @@ pub use lit::{
 
 pub mod kw;
 
+mod natspec;
+pub use natspec::NatSpecTag;
+
+pub mod pretty;
+pub use pretty::Printer;
+
 pub mod spanned;
 pub use spanned::Spanned;
 
+pub mod symbol;
+pub use symbol::{Interner, Symbol};
+
 mod stmt;
 pub use stmt::{
     AssemblyFlags, Block, CatchClause, ForInitStmt, Stmt, StmtAssembly, StmtBreak, StmtContinue,
@@ -67,7 +77,9 @@ pub use r#type::{Type, TypeArray, TypeFunction, TypeMapping, TypeTuple};
 pub(crate) mod utils;
 
 mod variable;
-pub use variable::{FieldList, ParameterList, Parameters, VariableDeclaration, VariableDefinition};
+pub use variable::{
+    AbiParam, FieldList, ParameterList, Parameters, VariableDeclaration, VariableDefinition,
+};
 
 #[cfg(feature = "visit")]
 pub mod visit;
@@ -87,13 +99,73 @@ pub use yul::{
 };
 
 /// Parse a Solidity [`proc_macro::TokenStream`] into a [`File`].
-pub fn parse(input: proc_macro::TokenStream) -> Result<File> {
+pub fn parse(input: proc_macro::TokenStream) -> syn::Result<File> {
     syn::parse(input)
 }
 
 /// Parse a Solidity [`proc_macro2::TokenStream`] into a [`File`].
-pub fn parse2(input: proc_macro2::TokenStream) -> Result<File> {
+pub fn parse2(input: proc_macro2::TokenStream) -> syn::Result<File> {
     syn::parse2(input)
 }
 
+/// Parse a Solidity source string into a [`File`].
+pub fn parse_file(s: &str) -> Result<File> {
+    syn::parse_str(s).map_err(Error::Parse)
+}
+
+/// Parse a Solidity source string into a [`File`], keeping the items that
+/// cannot be parsed as [`Item::Verbatim`]. See [`File::parse_tolerant`].
+pub fn parse_file_tolerant(s: &str) -> Result<File> {
+    syn::parse::Parser::parse_str(File::parse_tolerant, s).map_err(Error::Parse)
+}
+
+/// Parse a Solidity source string into an [`ItemFunction`].
+pub fn parse_function(s: &str) -> Result<ItemFunction> {
+    syn::parse_str(s).map_err(Error::Parse)
+}
+
+/// Parse a Solidity source string into a [`Type`].
+pub fn parse_type(s: &str) -> Result<Type> {
+    syn::parse_str(s).map_err(Error::Parse)
+}
+
 const DEBUG: bool = option_env!("SYN_SOLIDITY_DEBUG").is_some();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_str() {
+        let file = parse_file("contract C { function f() external; }").unwrap();
+        assert_eq!(file.items.len(), 1);
+        assert_eq!(
+            parse_function("function f(uint x);").unwrap().signature().unwrap(),
+            "f(uint256)"
+        );
+        assert_eq!(parse_type("uint256[]").unwrap().to_string(), "uint256[]");
+
+        let e = parse_function("function f() public private;").unwrap_err();
+        assert!(e.to_string().contains("`private`"), "{e}");
+        assert!(parse_type("mapping(uint =>)").is_err());
+        assert!(parse_file("contract {}").is_err());
+    }
+
+    #[test]
+    fn errors() {
+        let e = parse_type("uint7").unwrap_err();
+        assert!(matches!(e, Error::Parse(_)), "{e:?}");
+        assert_eq!(e.to_string(), "uintX must be a multiple of 8 up to 256");
+        let e: syn::Error = e.into();
+        assert_eq!(e.to_string(), "uintX must be a multiple of 8 up to 256");
+
+        let f = parse_function("function f() public;").unwrap();
+        let e = f.validate_for_interface().unwrap_err();
+        assert!(matches!(e, Error::Validation(_)), "{e:?}");
+        let e: Box<dyn std::error::Error> = Box::new(e);
+        assert_eq!(
+            e.to_string(),
+            "interface functions must be declared `external`, found `public`"
+        );
+    }
+}
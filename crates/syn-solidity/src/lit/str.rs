@@ -26,7 +26,7 @@ macro_rules! str_lit {
                     $(
                         f.write_str(stringify!($kw))?;
                     )?
-                    f.write_str(&value.value())?;
+                    value.token().fmt(f)?;
                 }
                 Ok(())
             }
@@ -277,6 +277,8 @@ macro_rules! make_visitor {
                         Type::Address(..)
                         | Type::Bool(_)
                         | Type::Uint(..)
+                        | Type::Fixed(..)
+                        | Type::Ufixed(..)
                         | Type::Int(..)
                         | Type::String(_)
                         | Type::Bytes(_)
@@ -350,6 +352,7 @@ macro_rules! make_visitor {
                         Item::Udt(udt) => v.visit_item_udt(udt),
                         Item::Using(using) => v.visit_using_directive(using),
                         Item::Variable(variable) => v.visit_variable_definition(variable),
+                        Item::Verbatim(_) => {}
                     }
                 }
 
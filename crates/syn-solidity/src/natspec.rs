@@ -0,0 +1,122 @@
+use crate::file::doc_comment;
+use syn::Attribute;
+
+/// A [NatSpec] documentation tag: `@param to The recipient.`
+///
+/// [NatSpec]: https://docs.soliditylang.org/en/latest/natspec-format.html
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NatSpecTag {
+    /// The name of the tag, without the leading `@`: `notice`, `param`,
+    /// `custom:foo`, etc.
+    ///
+    /// Untagged comments are `notice`s.
+    pub tag: String,
+    /// The parameter name of a `@param` tag.
+    pub name: Option<String>,
+    /// The tag's content. Continuation lines are joined with a single space.
+    pub content: String,
+}
+
+impl NatSpecTag {
+    /// Parses the NatSpec tags out of the `#[doc = "..."]` attributes, which
+    /// is how `///` and `/** */` comments are represented.
+    ///
+    /// Attributes that are not doc comments are ignored.
+    pub fn parse_attrs(attrs: &[Attribute]) -> Vec<Self> {
+        let mut tags = Vec::<Self>::new();
+        for doc in attrs.iter().filter_map(doc_comment) {
+            for line in doc.lines() {
+                // `/** */` comments keep the leading `*` of each line
+                let line = line.trim();
+                let line = line.strip_prefix('*').unwrap_or(line).trim();
+                if line.is_empty() {
+                    continue;
+                }
+
+                if let Some(tagged) = line.strip_prefix('@') {
+                    let (tag, mut content) = split_word(tagged);
+                    let mut name = None;
+                    if tag == "param" {
+                        let (param, rest) = split_word(content);
+                        name = Some(param.to_string());
+                        content = rest;
+                    }
+                    tags.push(Self { tag: tag.to_string(), name, content: content.to_string() });
+                } else if let Some(last) = tags.last_mut() {
+                    if !last.content.is_empty() {
+                        last.content.push(' ');
+                    }
+                    last.content.push_str(line);
+                } else {
+                    tags.push(Self { tag: "notice".into(), name: None, content: line.into() });
+                }
+            }
+        }
+        tags
+    }
+}
+
+fn split_word(s: &str) -> (&str, &str) {
+    match s.split_once(char::is_whitespace) {
+        Some((word, rest)) => (word, rest.trim_start()),
+        None => (s, ""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn tag(tag: &str, name: Option<&str>, content: &str) -> NatSpecTag {
+        NatSpecTag { tag: tag.into(), name: name.map(Into::into), content: content.into() }
+    }
+
+    #[test]
+    fn block_comments() {
+        let f: crate::ItemFunction = syn::parse_str(
+            "
+            /**
+             * Does a thing.
+             * @dev Implementation detail,
+             *      continued here.
+             * @custom:security high
+             */
+            function f();
+            ",
+        )
+        .unwrap();
+        assert_eq!(
+            f.docs(),
+            [
+                tag("notice", None, "Does a thing."),
+                tag("dev", None, "Implementation detail, continued here."),
+                tag("custom:security", None, "high"),
+            ]
+        );
+    }
+
+    #[test]
+    fn block_comment_params() {
+        let f: crate::ItemFunction = syn::parse_str(
+            "
+            /** @notice Transfers `amount` tokens
+             *  to `to`, reverting on failure.
+             * @param to The recipient.
+             * @param amount The amount,
+             *        in wei.
+             */
+            function transfer(address to, uint256 amount);
+            ",
+        )
+        .unwrap();
+        assert_eq!(
+            f.docs(),
+            [
+                tag("notice", None, "Transfers `amount` tokens to `to`, reverting on failure."),
+                tag("param", Some("to"), "The recipient."),
+                tag("param", Some("amount"), "The amount, in wei."),
+            ]
+        );
+    }
+}
@@ -0,0 +1,259 @@
+//! Pretty-printing of Solidity source code.
+//!
+//! The [`Display`](std::fmt::Display) implementations of the AST print each item
+//! on a single line where possible. A [`Printer`] builds on them to lay out
+//! whole files, with the members of contracts each on their own line and
+//! indented. [`File`]'s `Display` implementation uses the default printer.
+//!
+//! Statements and expressions are not printed yet: function bodies and
+//! variable initializers are replaced with `{ <stmts> }` and `<expr>`
+//! placeholders, so only the output of declarations can be parsed back.
+
+use crate::{file::doc_comment, File, Item, ItemContract};
+use quote::ToTokens;
+use syn::{AttrStyle, Attribute, MacroDelimiter, Meta, Path};
+
+/// A pretty-printer with a configurable indentation width.
+///
+/// Contract members are printed on their own lines, indented by one level, and
+/// separated by blank lines, as are the items of a file. Attributes are
+/// emitted above their item in source order, with doc comments as `///`
+/// comments, and the inner attributes of a file at its top.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Printer {
+    indent: usize,
+}
+
+impl Default for Printer {
+    /// Indents by 4 spaces.
+    fn default() -> Self {
+        Self::new(4)
+    }
+}
+
+impl Printer {
+    /// Creates a new printer that indents each level by `indent` spaces.
+    #[inline]
+    pub const fn new(indent: usize) -> Self {
+        Self { indent }
+    }
+
+    /// Returns the number of spaces of each indentation level.
+    #[inline]
+    pub const fn indent(&self) -> usize {
+        self.indent
+    }
+
+    /// Pretty-prints a file. Every line, including the last one, ends with a
+    /// newline.
+    pub fn print_file(&self, file: &File) -> String {
+        let mut out = String::new();
+        self.attrs(&file.attrs, 0, &mut out);
+        if !file.attrs.is_empty() && !file.items.is_empty() {
+            out.push('\n');
+        }
+        self.items(&file.items, 0, &mut out);
+        out
+    }
+
+    /// Pretty-prints a single item. Every line, including the last one, ends
+    /// with a newline.
+    pub fn print_item(&self, item: &Item) -> String {
+        let mut out = String::new();
+        self.item(item, 0, &mut out);
+        out
+    }
+
+    fn items(&self, items: &[Item], depth: usize, out: &mut String) {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            self.item(item, depth, out);
+        }
+    }
+
+    fn item(&self, item: &Item, depth: usize, out: &mut String) {
+        self.attrs(item.attrs().map_or(&[], Vec::as_slice), depth, out);
+        match item {
+            Item::Contract(contract) => self.contract(contract, depth, out),
+            item => self.line(&item.to_string(), depth, out),
+        }
+    }
+
+    fn contract(&self, contract: &ItemContract, depth: usize, out: &mut String) {
+        let header = contract.header();
+        if contract.body.is_empty() {
+            self.line(&format!("{header} {{}}"), depth, out);
+        } else {
+            self.line(&format!("{header} {{"), depth, out);
+            self.items(&contract.body, depth + 1, out);
+            self.line("}", depth, out);
+        }
+    }
+
+    fn attrs(&self, attrs: &[Attribute], depth: usize, out: &mut String) {
+        for attr in attrs {
+            let bang = match attr.style {
+                AttrStyle::Outer => "",
+                AttrStyle::Inner(_) => "!",
+            };
+            match doc_comment(attr) {
+                Some(doc) => {
+                    let slash = if bang.is_empty() { "/" } else { "!" };
+                    for line in doc.lines() {
+                        self.line(&format!("//{slash}{line}"), depth, out);
+                    }
+                }
+                None => self.line(&format!("#{bang}[{}]", meta(&attr.meta)), depth, out),
+            }
+        }
+    }
+
+    /// Writes every line of `s` indented by `depth` levels.
+    fn line(&self, s: &str, depth: usize, out: &mut String) {
+        for line in s.lines() {
+            if !line.is_empty() {
+                out.extend(std::iter::repeat(' ').take(depth * self.indent));
+            }
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+}
+
+/// Formats an attribute's contents without the spaces that
+/// [`TokenStream`](proc_macro2::TokenStream)'s `Display` puts after the path.
+fn meta(meta: &Meta) -> String {
+    match meta {
+        Meta::Path(path) => self::path(path),
+        Meta::List(list) => {
+            let (open, close) = match list.delimiter {
+                MacroDelimiter::Paren(_) => ('(', ')'),
+                MacroDelimiter::Brace(_) => ('{', '}'),
+                MacroDelimiter::Bracket(_) => ('[', ']'),
+            };
+            format!("{}{open}{}{close}", path(&list.path), list.tokens)
+        }
+        Meta::NameValue(nv) => format!("{} = {}", path(&nv.path), nv.value.to_token_stream()),
+    }
+}
+
+fn path(path: &Path) -> String {
+    let mut s = String::new();
+    if path.leading_colon.is_some() {
+        s.push_str("::");
+    }
+    for (i, segment) in path.segments.iter().enumerate() {
+        if i > 0 {
+            s.push_str("::");
+        }
+        s.push_str(&segment.to_token_stream().to_string());
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn contract() {
+        let src = "
+            pragma abicoder v2;
+            /// A token.
+            interface IToken is IERC20 {
+                /// Returns the balance.
+                function balanceOf(address owner) external view returns (uint256);
+                function transfer(address to, uint amount) external returns (bool);
+            }
+            contract Empty {}
+        ";
+        let file = crate::parse_file(src).unwrap();
+        let printed = Printer::new(2).print_file(&file);
+        assert_eq!(
+            printed,
+            "\
+pragma abicoder v2;
+
+/// A token.
+interface IToken is IERC20 {
+  /// Returns the balance.
+  function balanceOf(address owner) external view returns (uint256);
+
+  function transfer(address to, uint amount) external returns (bool);
+}
+
+contract Empty {}
+"
+        );
+
+        let reparsed = crate::parse_file(&printed).unwrap();
+        assert_eq!(reparsed.items.len(), file.items.len());
+        assert_eq!(Printer::new(2).print_file(&reparsed), printed);
+
+        let printed = Printer::default().print_item(&file.items[1]);
+        assert!(printed.starts_with("/// A token.\ninterface IToken is IERC20 {\n"), "{printed}");
+        assert!(printed.contains("\n    function transfer("), "{printed}");
+        assert_eq!(printed.lines().count(), 7);
+    }
+
+    #[test]
+    fn attributes() {
+        let src = "
+            #![sol(all_derives)]
+            //! Interfaces.
+
+            /// A token.
+            #[sol(rpc)]
+            interface IToken {
+                #[sol(rename = \"bal\")]
+                function balanceOf(address owner) external view returns (uint256);
+            }
+        ";
+        let file = crate::parse_file(src).unwrap();
+        let printed = Printer::default().print_file(&file);
+        assert_eq!(
+            printed,
+            "\
+#![sol(all_derives)]
+//! Interfaces.
+
+/// A token.
+#[sol(rpc)]
+interface IToken {
+    #[sol(rename = \"bal\")]
+    function balanceOf(address owner) external view returns (uint256);
+}
+"
+        );
+
+        let reparsed = crate::parse_file(&printed).unwrap();
+        assert_eq!(reparsed.attrs, file.attrs);
+        assert_eq!(reparsed.items[0].attrs(), file.items[0].attrs());
+        assert_eq!(Printer::default().print_file(&reparsed), printed);
+    }
+
+    #[test]
+    fn placeholders() {
+        let file = crate::parse_file(
+            "contract C { uint x = 1; uint public y; function f() external { x = 2; } }",
+        )
+        .unwrap();
+        let printed = Printer::default().print_file(&file);
+        assert_eq!(
+            printed,
+            "\
+contract C {
+    uint x = <expr>;
+
+    uint public y;
+
+    function f() external { <stmts> }
+}
+"
+        );
+        assert!(crate::parse_file(&printed).is_err());
+    }
+}
@@ -366,3 +366,32 @@ pub fn set_spans<T: Spanned, I: IntoIterator<Item = T>>(items: I, span: Span) {
         item.set_span(span);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ItemFunction, Parameters, Returns, SolIdent, Type};
+
+    fn require_spanned<T: Spanned>(_: &T) {}
+
+    #[test]
+    fn spanned() {
+        let mut f: ItemFunction =
+            syn::parse_str("function f(uint a) external view returns (bool);").unwrap();
+        require_spanned::<ItemFunction>(&f);
+        require_spanned::<Parameters<Token![,]>>(&f.parameters);
+        require_spanned::<Returns>(f.returns.as_ref().unwrap());
+        require_spanned(&f.attributes);
+        require_spanned::<Type>(&f.parameters[0].ty);
+        require_spanned::<SolIdent>(f.name.as_ref().unwrap());
+
+        // a function is spanned by its name
+        assert_eq!(f.span().start().column, 9);
+        assert_eq!(f.parameters.span().start().column, 11);
+        assert_eq!(f.attributes.span().start().column, 19);
+
+        let span = f.parameters[0].ty.span();
+        f.set_span(span);
+        assert_eq!(f.name.as_ref().unwrap().span().start().column, 11);
+    }
+}
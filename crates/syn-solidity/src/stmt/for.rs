@@ -67,7 +67,7 @@ impl Spanned for StmtFor {
 #[derive(Clone, Debug)]
 pub enum ForInitStmt {
     Empty(Token![;]),
-    VarDecl(StmtVarDecl),
+    VarDecl(Box<StmtVarDecl>),
     Expr(StmtExpr),
 }
 
@@ -78,7 +78,7 @@ impl Parse for ForInitStmt {
             input.parse().map(Self::Empty)
         } else {
             match StmtVarDecl::parse_or_expr(input)? {
-                Stmt::VarDecl(decl) => Ok(Self::VarDecl(decl)),
+                Stmt::VarDecl(decl) => Ok(Self::VarDecl(Box::new(decl))),
                 Stmt::Expr(expr) => Ok(Self::Expr(expr)),
                 s => unreachable!("StmtVarDecl::parse_or_expr: invalid output {s:?}"),
             }
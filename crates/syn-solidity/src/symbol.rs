@@ -0,0 +1,111 @@
+//! String interning for identifiers.
+//!
+//! [`SolIdent::as_string`] allocates a new string every time it is called.
+//! When processing large amounts of code, an [`Interner`] can be used instead
+//! to store each distinct identifier only once, and refer to it with a cheap
+//! [`Symbol`] handle.
+
+use crate::SolIdent;
+use std::{collections::HashMap, rc::Rc};
+
+/// A handle to a string stored in an [`Interner`].
+///
+/// Symbols are only meaningful for the interner that created them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Returns the index of the symbol in its interner.
+    #[inline]
+    pub const fn as_u32(self) -> u32 {
+        self.0
+    }
+}
+
+/// A string interner, which stores each distinct string once.
+#[derive(Clone, Debug, Default)]
+pub struct Interner {
+    symbols: HashMap<Rc<str>, Symbol>,
+    strings: Vec<Rc<str>>,
+}
+
+impl Interner {
+    /// Creates a new, empty interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Interns `s`, returning the symbol of the existing copy if there is one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `u32::MAX` strings are interned.
+    pub fn intern(&mut self, s: &str) -> Symbol {
+        if let Some(&symbol) = self.symbols.get(s) {
+            return symbol;
+        }
+        let symbol = Symbol(self.strings.len().try_into().expect("too many interned strings"));
+        let s: Rc<str> = s.into();
+        self.strings.push(s.clone());
+        self.symbols.insert(s, symbol);
+        symbol
+    }
+
+    /// Interns the identifier, without its `r#` prefix if present. See
+    /// [`SolIdent::as_string`].
+    pub fn intern_ident(&mut self, ident: &SolIdent) -> Symbol {
+        self.intern(&ident.as_string())
+    }
+
+    /// Returns the string of the given symbol.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the symbol was not created by this interner.
+    #[track_caller]
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        &self.strings[symbol.0 as usize]
+    }
+
+    /// Returns the symbol of `s`, if it has been interned.
+    pub fn get(&self, s: &str) -> Option<Symbol> {
+        self.symbols.get(s).copied()
+    }
+
+    /// Returns the number of interned strings.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if no strings have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intern() {
+        let mut interner = Interner::new();
+        let a = interner.intern_ident(&SolIdent::new("balance"));
+        let b = interner.intern_ident(&syn::parse_str("balance").unwrap());
+        let c = interner.intern("owner");
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.get("balance"), Some(a));
+        assert_eq!(interner.get("missing"), None);
+
+        assert_eq!(interner.resolve(a), "balance");
+        assert!(std::ptr::eq(interner.resolve(a), interner.resolve(b)));
+
+        // raw identifiers are interned without their prefix
+        let ty = SolIdent::new("mod");
+        assert_ne!(ty.to_string(), "mod");
+        assert_eq!(ty.as_str_in(&mut interner), "mod");
+        assert_eq!(interner.len(), 3);
+    }
+}
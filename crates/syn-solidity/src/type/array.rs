@@ -1,7 +1,7 @@
 use crate::{Expr, Lit, LitNumber, Spanned, Type};
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
 use std::{
-    fmt,
+    fmt::{self, Write},
     hash::{Hash, Hasher},
     num::NonZeroUsize,
 };
@@ -9,7 +9,7 @@ use syn::{
     bracketed,
     parse::{discouraged::Speculative, Parse, ParseStream},
     token::Bracket,
-    Result,
+    Error, Result,
 };
 
 /// An array type.
@@ -18,11 +18,17 @@ pub struct TypeArray {
     pub ty: Box<Type>,
     pub bracket_token: Bracket,
     pub size: Option<Box<Expr>>,
+    /// The raw tokens of the size as parsed, if it is an expression other than
+    /// an integer literal. Only set by [`parse_nested`](Self::parse_nested),
+    /// and read through [`size_tokens`](Self::size_tokens).
+    size_tokens: Option<TokenStream>,
 }
 
 impl PartialEq for TypeArray {
     fn eq(&self, other: &Self) -> bool {
-        self.ty == other.ty && self.size() == other.size()
+        self.ty == other.ty
+            && self.size_as_usize() == other.size_as_usize()
+            && self.size_expr_string() == other.size_expr_string()
     }
 }
 
@@ -31,7 +37,8 @@ impl Eq for TypeArray {}
 impl Hash for TypeArray {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.ty.hash(state);
-        self.size().hash(state);
+        self.size_as_usize().hash(state);
+        self.size_expr_string().hash(state);
     }
 }
 
@@ -41,6 +48,8 @@ impl fmt::Display for TypeArray {
         f.write_str("[")?;
         if let Some(s) = self.size_lit() {
             f.write_str(s.base10_digits())?;
+        } else if let Some(tokens) = self.size_tokens() {
+            tokens.fmt(f)?;
         }
         f.write_str("]")
     }
@@ -48,7 +57,13 @@ impl fmt::Display for TypeArray {
 
 impl fmt::Debug for TypeArray {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("TypeArray").field(&self.ty).field(&self.size()).finish()
+        let mut t = f.debug_tuple("TypeArray");
+        t.field(&self.ty);
+        match self.size_tokens() {
+            Some(tokens) => t.field(&tokens.to_string()),
+            None => t.field(&self.size_as_usize()),
+        };
+        t.finish()
     }
 }
 
@@ -76,18 +91,59 @@ impl Spanned for TypeArray {
 
 impl TypeArray {
     /// Returns the size of the array, or None if dynamic.
+    ///
+    /// This is also None if the size is an unevaluated expression; use
+    /// [`has_size_expr`](Self::has_size_expr) to tell both cases apart. Same
+    /// as [`size_as_usize`](Self::size_as_usize).
     pub fn size(&self) -> Option<usize> {
+        self.size_as_usize()
+    }
+
+    /// Returns the size of the array if it is an integer literal, or None if
+    /// the array is dynamic or its size is an expression.
+    pub fn size_as_usize(&self) -> Option<usize> {
         self.size_lit().map(|s| s.base10_parse().unwrap())
     }
 
-    /// Returns the size of the array, or None if dynamic.
+    /// Returns the size of the array if it is an integer literal, or None if
+    /// the array is dynamic or its size is an expression.
     pub fn size_lit(&self) -> Option<&LitNumber> {
-        self.size.as_ref().map(|s| match &**s {
-            Expr::Lit(Lit::Number(n)) => n,
-            _ => panic!("unevaluated literal in array size"),
+        self.size.as_ref().and_then(|s| match &**s {
+            Expr::Lit(Lit::Number(n)) => Some(n),
+            _ => None,
         })
     }
 
+    /// Returns `true` if the array has a size that is not an integer literal.
+    pub fn has_size_expr(&self) -> bool {
+        self.size.is_some() && self.size_lit().is_none()
+    }
+
+    /// Returns the tokens of the size expression as they were parsed, if the
+    /// array has a size that is not an integer literal, like `N` or `2 * 3`.
+    ///
+    /// This is None for arrays whose size expression was not parsed from
+    /// tokens.
+    pub fn size_tokens(&self) -> Option<&TokenStream> {
+        self.size_tokens.as_ref().filter(|_| self.has_size_expr())
+    }
+
+    fn size_expr_string(&self) -> Option<String> {
+        self.size_tokens().map(ToString::to_string)
+    }
+
+    /// Writes the brackets of the array, with its literal size or its size
+    /// expression tokens, if any: `[]`, `[2]` or `[N]`.
+    pub(crate) fn write_brackets(&self, s: &mut String) {
+        s.push('[');
+        if let Some(size) = self.size_as_usize() {
+            write!(s, "{size}").unwrap();
+        } else if let Some(tokens) = self.size_tokens() {
+            write!(s, "{tokens}").unwrap();
+        }
+        s.push(']');
+    }
+
     /// See [`Type::is_abi_dynamic`].
     pub fn is_abi_dynamic(&self) -> bool {
         match self.size {
@@ -99,32 +155,94 @@ impl TypeArray {
     /// Parses an array type from the given input stream, wrapping `ty` with it.
     pub fn parse_nested(ty: Box<Type>, input: ParseStream<'_>) -> Result<Self> {
         let content;
-        Ok(Self {
-            ty,
-            bracket_token: bracketed!(content in input),
-            size: {
-                if content.is_empty() {
-                    None
-                } else {
-                    let fork = content.fork();
-                    let sz = match fork.parse::<syn::LitInt>() {
-                        Ok(lit) => lit,
-                        Err(e) => {
-                            return Err(match fork.parse::<Expr>() {
-                                Ok(_) => syn::Error::new(
-                                    e.span(),
-                                    "generic expressions are not supported in array type sizes",
-                                ),
-                                Err(e) => e,
-                            })
-                        }
-                    };
+        let bracket_token = bracketed!(content in input);
+        let (size, size_tokens) = if content.is_empty() {
+            (None, None)
+        } else {
+            let fork = content.fork();
+            match fork.parse::<syn::LitInt>() {
+                Ok(sz) if fork.is_empty() => {
                     content.advance_to(&fork);
-                    // Validate the size
+                    // Validate the size. Underscores and hex literals are
+                    // normalized to base 10 by `LitInt`.
+                    if sz.base10_parse::<usize>().is_err() && !sz.base10_digits().starts_with('-') {
+                        let msg = format!("array size is too large, the maximum is {}", usize::MAX);
+                        return Err(Error::new(sz.span(), msg));
+                    }
                     sz.base10_parse::<NonZeroUsize>()?;
-                    Some(Box::new(Expr::Lit(Lit::Number(LitNumber::Int(sz)))))
+                    (Some(Box::new(Expr::Lit(Lit::Number(LitNumber::Int(sz))))), None)
                 }
-            },
-        })
+                _ => {
+                    let tokens = content.fork().parse::<TokenStream>()?;
+                    (Some(Box::new(content.parse::<Expr>()?)), Some(tokens))
+                }
+            }
+        };
+        Ok(Self { ty, bracket_token, size, size_tokens })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn parse(s: &str) -> TypeArray {
+        match syn::parse_str(s).unwrap() {
+            Type::Array(array) => array,
+            ty => panic!("{ty:?}"),
+        }
+    }
+
+    #[test]
+    fn sizes() {
+        let fixed = parse("uint256[4]");
+        assert_eq!(fixed.size_as_usize(), Some(4));
+        assert_eq!(fixed.size(), Some(4));
+        assert!(!fixed.has_size_expr());
+
+        let dynamic = parse("uint256[]");
+        assert_eq!(dynamic.size_as_usize(), None);
+        assert!(dynamic.is_abi_dynamic());
+
+        for (s, display, size) in
+            [("uint256[N]", "uint256[N]", "N"), ("uint8[2*3]", "uint8[2 * 3]", "2 * 3")]
+        {
+            let array = parse(s);
+            assert!(array.has_size_expr());
+            assert!(array.size.is_some());
+            assert_eq!(array.size_as_usize(), None);
+            assert_eq!(array.size(), None);
+            assert_eq!(array.size_tokens().unwrap().to_string(), size);
+            assert!(!array.is_abi_dynamic());
+            assert_eq!(array.to_string(), display);
+            assert_eq!(parse(display), array);
+        }
+        assert_ne!(parse("uint256[N]"), parse("uint256[M]"));
+
+        // replacing the size expression with a literal discards the tokens
+        let mut array = parse("uint256[N]");
+        array.size = parse("uint256[4]").size;
+        assert!(!array.has_size_expr());
+        assert!(array.size_tokens().is_none());
+        assert_eq!(array, parse("uint256[4]"));
+
+        syn::parse_str::<Type>("uint256[0]").unwrap_err();
+    }
+
+    #[test]
+    fn literal_sizes() {
+        for (s, size) in [("uint8[1_000]", 1000), ("uint8[0x10]", 16), ("uint8[0x1_00]", 256)] {
+            let array = parse(s);
+            assert!(!array.has_size_expr(), "{s}");
+            assert_eq!(array.size(), Some(size), "{s}");
+            assert_eq!(array.to_string(), format!("uint8[{size}]"));
+        }
+
+        let e = syn::parse_str::<Type>("uint8[100000000000000000000000]").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            format!("array size is too large, the maximum is {}", usize::MAX)
+        );
     }
 }
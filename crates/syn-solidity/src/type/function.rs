@@ -1,4 +1,4 @@
-use crate::{kw, FunctionAttributes, ParameterList, Returns, Spanned};
+use crate::{kw, FunctionAttributes, ParameterList, Returns, Spanned, Visibility};
 use proc_macro2::Span;
 use std::{
     fmt,
@@ -8,7 +8,7 @@ use syn::{
     parenthesized,
     parse::{Parse, ParseStream},
     token::Paren,
-    Result,
+    Error, Result,
 };
 
 /// A function type: `function() returns (string memory)`.
@@ -51,7 +51,7 @@ impl fmt::Display for TypeFunction {
             write!(f, " {attr}")?;
         }
 
-        if let Some(returns) = &self.returns {
+        if let Some(returns) = self.returns.as_ref().filter(|r| !r.returns.is_empty()) {
             write!(f, " {returns}")?;
         }
 
@@ -72,13 +72,20 @@ impl fmt::Debug for TypeFunction {
 impl Parse for TypeFunction {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let content;
-        Ok(Self {
+        let this = Self {
             function_token: input.parse()?,
             paren_token: parenthesized!(content in input),
             arguments: content.parse()?,
-            attributes: input.parse()?,
+            attributes: input.call(FunctionAttributes::parse_function_type)?,
             returns: input.call(Returns::parse_opt)?,
-        })
+        };
+        match this.attributes.visibility() {
+            Some(vis @ (Visibility::Public(_) | Visibility::Private(_))) => Err(Error::new(
+                vis.span(),
+                "function types can only be declared `internal` or `external`",
+            )),
+            _ => Ok(this),
+        }
     }
 }
 
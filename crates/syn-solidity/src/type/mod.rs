@@ -1,10 +1,14 @@
-use crate::{kw, sol_path, SolPath, Spanned};
-use proc_macro2::Span;
+use crate::{kw, sol_path, AbiParam, ParameterList, SolPath, Spanned, StructRegistry};
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
 use std::{
+    cell::Cell,
+    cmp::Ordering,
     fmt,
     fmt::Write,
     hash::{Hash, Hasher},
     num::{IntErrorKind, NonZeroU16},
+    str::FromStr,
 };
 use syn::{
     ext::IdentExt,
@@ -31,7 +35,6 @@ pub use tuple::TypeTuple;
 /// <https://docs.soliditylang.org/en/latest/grammar.html#a4.SolidityParser.typeName>
 #[derive(Clone)]
 pub enum Type {
-    // TODO: `fixed` and `ufixed`
     /// `address $(payable)?`
     Address(Span, Option<kw::payable>),
     /// `bool`
@@ -41,7 +44,7 @@ pub enum Type {
 
     /// `bytes`
     Bytes(Span),
-    /// `bytes<size>`
+    /// `bytes<size>`, or `byte`
     FixedBytes(Span, NonZeroU16),
 
     /// `int[size]`
@@ -49,6 +52,11 @@ pub enum Type {
     /// `uint[size]`
     Uint(Span, Option<NonZeroU16>),
 
+    /// `fixed[<bits>x<decimals>]`
+    Fixed(Span, Option<(NonZeroU16, u8)>),
+    /// `ufixed[<bits>x<decimals>]`
+    Ufixed(Span, Option<(NonZeroU16, u8)>),
+
     /// `$ty[$($size)?]`
     Array(TypeArray),
     /// `$(tuple)? ( $($types,)* )`
@@ -71,8 +79,11 @@ impl PartialEq for Type {
             (Self::Bytes { .. }, Self::Bytes { .. }) => true,
 
             (Self::FixedBytes(_, a), Self::FixedBytes(_, b)) => a == b,
-            (Self::Int(_, a), Self::Int(_, b)) => a == b,
-            (Self::Uint(_, a), Self::Uint(_, b)) => a == b,
+            // aliases compare equal to their canonical form: `uint == uint256`
+            (Self::Int(_, a), Self::Int(_, b)) => int_size(*a) == int_size(*b),
+            (Self::Uint(_, a), Self::Uint(_, b)) => int_size(*a) == int_size(*b),
+            (Self::Fixed(_, a), Self::Fixed(_, b)) => fixed_size(*a) == fixed_size(*b),
+            (Self::Ufixed(_, a), Self::Ufixed(_, b)) => fixed_size(*a) == fixed_size(*b),
 
             (Self::Tuple(a), Self::Tuple(b)) => a == b,
             (Self::Array(a), Self::Array(b)) => a == b,
@@ -87,6 +98,55 @@ impl PartialEq for Type {
 
 impl Eq for Type {}
 
+/// Types are ordered by their [canonical string](Type::canonical_string), so
+/// that sorting is reproducible and ignores spans.
+///
+/// To stay consistent with [`Eq`], mappings are compared without their key
+/// and value names, and function types by their arguments and return values,
+/// rather than all being equal to `function`.
+impl PartialOrd for Type {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Type {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        let (mut a, mut b) = (String::new(), String::new());
+        self.ord_key(&mut a);
+        other.ord_key(&mut b);
+        a.cmp(&b)
+    }
+}
+
+/// Writes the [`Ord`] key of a parameter list, which includes everything that
+/// its [`PartialEq`] implementation compares.
+fn params_ord_key(params: &ParameterList, s: &mut String) {
+    s.push('(');
+    for (i, param) in params.iter().enumerate() {
+        if i > 0 {
+            s.push(',');
+        }
+        for attr in &param.attrs {
+            write!(s, "{} ", attr.to_token_stream()).unwrap();
+        }
+        param.ty.ord_key(s);
+        if let Some(storage) = &param.storage {
+            write!(s, " {storage}").unwrap();
+        }
+        if let Some(name) = &param.name {
+            write!(s, " {name}").unwrap();
+        }
+    }
+    if params.trailing_punct() {
+        s.push(',');
+    }
+    s.push(')');
+}
+
 impl Hash for Type {
     fn hash<H: Hasher>(&self, state: &mut H) {
         std::mem::discriminant(self).hash(state);
@@ -94,8 +154,10 @@ impl Hash for Type {
             Self::Address(..) | Self::Bool(_) | Self::String(_) | Self::Bytes(_) => {}
 
             Self::FixedBytes(_, size) => size.hash(state),
-            Self::Int(_, size) => size.hash(state),
-            Self::Uint(_, size) => size.hash(state),
+            Self::Int(_, size) => int_size(*size).hash(state),
+            Self::Uint(_, size) => int_size(*size).hash(state),
+            Self::Fixed(_, size) => fixed_size(*size).hash(state),
+            Self::Ufixed(_, size) => fixed_size(*size).hash(state),
 
             Self::Tuple(tuple) => tuple.hash(state),
             Self::Array(array) => array.hash(state),
@@ -106,25 +168,73 @@ impl Hash for Type {
     }
 }
 
+/// Returns the size of an integer type, resolving the `int`/`uint` alias.
+#[inline]
+fn int_size(size: Option<NonZeroU16>) -> u16 {
+    size.map_or(256, NonZeroU16::get)
+}
+
+/// Returns the size of a fixed-point type, resolving the `fixed`/`ufixed`
+/// alias.
+#[inline]
+fn fixed_size(size: Option<(NonZeroU16, u8)>) -> (u16, u8) {
+    size.map_or((128, 18), |(m, n)| (m.get(), n))
+}
+
+/// Formats the type as its [canonical string](Type::canonical_string):
+/// `Type("uint256[]")`. See [`Type::debug_verbose`] for the full tree.
 impl fmt::Debug for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // array size expressions cannot be canonicalized
+        let s = if self.has_size_exprs() { self.to_string() } else { self.canonical_string() };
+        f.debug_tuple("Type").field(&s).finish()
+    }
+}
+
+/// Formats the variant tree of a [`Type`]. See [`Type::debug_verbose`].
+struct DebugVerbose<'a>(&'a Type);
+
+impl fmt::Debug for DebugVerbose<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("Type::")?;
-        match self {
-            Self::Address(_, None) => f.write_str("Address"),
-            Self::Address(_, Some(_)) => f.write_str("AddressPayable"),
-            Self::Bool(_) => f.write_str("Bool"),
-            Self::String(_) => f.write_str("String"),
-            Self::Bytes(_) => f.write_str("Bytes"),
+        match self.0 {
+            Type::Address(_, None) => f.write_str("Address"),
+            Type::Address(_, Some(_)) => f.write_str("AddressPayable"),
+            Type::Bool(_) => f.write_str("Bool"),
+            Type::String(_) => f.write_str("String"),
+            Type::Bytes(_) => f.write_str("Bytes"),
 
-            Self::FixedBytes(_, size) => f.debug_tuple("FixedBytes").field(size).finish(),
-            Self::Int(_, size) => f.debug_tuple("Int").field(size).finish(),
-            Self::Uint(_, size) => f.debug_tuple("Uint").field(size).finish(),
+            Type::FixedBytes(_, size) => f.debug_tuple("FixedBytes").field(size).finish(),
+            Type::Int(_, size) => f.debug_tuple("Int").field(size).finish(),
+            Type::Uint(_, size) => f.debug_tuple("Uint").field(size).finish(),
+            Type::Fixed(_, size) => f.debug_tuple("Fixed").field(size).finish(),
+            Type::Ufixed(_, size) => f.debug_tuple("Ufixed").field(size).finish(),
 
-            Self::Tuple(tuple) => tuple.fmt(f),
-            Self::Array(array) => array.fmt(f),
-            Self::Function(function) => function.fmt(f),
-            Self::Mapping(mapping) => mapping.fmt(f),
-            Self::Custom(custom) => f.debug_tuple("Custom").field(custom).finish(),
+            Type::Tuple(tuple) => {
+                let mut t = f.debug_tuple("Tuple");
+                for ty in &tuple.types {
+                    t.field(&ty.debug_verbose());
+                }
+                t.finish()
+            }
+            Type::Array(array) => {
+                let mut t = f.debug_tuple("Array");
+                t.field(&array.ty.debug_verbose());
+                match array.size_tokens() {
+                    Some(tokens) => t.field(&tokens.to_string()),
+                    None => t.field(&array.size_as_usize()),
+                };
+                t.finish()
+            }
+            Type::Function(function) => function.fmt(f),
+            Type::Mapping(mapping) => f
+                .debug_struct("Mapping")
+                .field("key", &mapping.key.debug_verbose())
+                .field("key_name", &mapping.key_name)
+                .field("value", &mapping.value.debug_verbose())
+                .field("value_name", &mapping.value_name)
+                .finish(),
+            Type::Custom(custom) => f.debug_tuple("Custom").field(custom).finish(),
         }
     }
 }
@@ -133,7 +243,8 @@ impl fmt::Debug for Type {
 impl fmt::Display for Type {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Address(_, _) => f.write_str("address"),
+            Self::Address(_, None) => f.write_str("address"),
+            Self::Address(_, Some(_)) => f.write_str("address payable"),
             Self::Bool(_) => f.write_str("bool"),
             Self::String(_) => f.write_str("string"),
             Self::Bytes(_) => f.write_str("bytes"),
@@ -141,23 +252,62 @@ impl fmt::Display for Type {
             Self::FixedBytes(_, size) => write!(f, "bytes{size}"),
             Self::Int(_, size) => write_opt(f, "int", *size),
             Self::Uint(_, size) => write_opt(f, "uint", *size),
+            Self::Fixed(_, size) => write_fixed(f, "fixed", *size),
+            Self::Ufixed(_, size) => write_fixed(f, "ufixed", *size),
 
             Self::Tuple(tuple) => tuple.fmt(f),
             Self::Array(array) => array.fmt(f),
-            Self::Function(_) => f.write_str("function"),
+            Self::Function(function) => function.fmt(f),
             Self::Mapping(mapping) => mapping.fmt(f),
             Self::Custom(custom) => custom.fmt(f),
         }
     }
 }
 
+/// Formats a [`Type`] in its canonical form. See [`Type::display_canonical`].
+struct DisplayCanonical<'a>(&'a Type);
+
+impl fmt::Display for DisplayCanonical<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.canonical_string())
+    }
+}
+
+/// Serializes the type as its [canonical string](Type::canonical_string).
+#[cfg(feature = "serde")]
+impl serde::Serialize for Type {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.canonical_string())
+    }
+}
+
+/// Parses the type from a string. All spans are set to [`Span::call_site()`].
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Type {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        let mut ty = syn::parse_str::<Self>(&s).map_err(serde::de::Error::custom)?;
+        ty.set_span(Span::call_site());
+        Ok(ty)
+    }
+}
+
 impl Parse for Type {
+    /// Parses a type, erroring if it is nested deeper than the maximum depth.
+    /// See [`Type::parse_with_max_depth`].
     fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let mut guard = DepthGuard::enter(input)?;
         let mut candidate = Self::parse_simple(input)?;
 
         // while the next token is a bracket, parse an array size and nest the
         // candidate into an array
         while input.peek(Bracket) {
+            guard.deepen(input)?;
             candidate = Self::Array(TypeArray::parse_nested(Box::new(candidate), input)?);
         }
 
@@ -176,7 +326,9 @@ impl Spanned for Type {
             | Self::Bytes(span)
             | Self::FixedBytes(span, _)
             | Self::Int(span, _)
-            | Self::Uint(span, _) => *span,
+            | Self::Uint(span, _)
+            | Self::Fixed(span, _)
+            | Self::Ufixed(span, _) => *span,
             Self::Tuple(tuple) => tuple.span(),
             Self::Array(array) => array.span(),
             Self::Function(function) => function.span(),
@@ -198,7 +350,9 @@ impl Spanned for Type {
             | Self::Bytes(span)
             | Self::FixedBytes(span, _)
             | Self::Int(span, _)
-            | Self::Uint(span, _) => *span = new_span,
+            | Self::Uint(span, _)
+            | Self::Fixed(span, _)
+            | Self::Ufixed(span, _) => *span = new_span,
 
             Self::Tuple(tuple) => tuple.set_span(new_span),
             Self::Array(array) => array.set_span(new_span),
@@ -209,7 +363,110 @@ impl Spanned for Type {
     }
 }
 
+impl FromStr for Type {
+    type Err = crate::Error;
+
+    /// Parses a type from a string. See [`parse_type`](crate::parse_type).
+    #[inline]
+    fn from_str(s: &str) -> crate::Result<Self> {
+        crate::parse_type(s)
+    }
+}
+
+impl TryFrom<&str> for Type {
+    type Error = crate::Error;
+
+    #[inline]
+    fn try_from(s: &str) -> crate::Result<Self> {
+        s.parse()
+    }
+}
+
 impl Type {
+    /// Returns a [`Debug`](fmt::Debug) implementation that formats the full
+    /// variant tree of the type, like `Type::Array(Type::Uint(Some(256)), None)`,
+    /// instead of its canonical string.
+    pub fn debug_verbose(&self) -> impl fmt::Debug + '_ {
+        DebugVerbose(self)
+    }
+
+    /// Writes the string that [`Ord`] compares types by. See the docs on the
+    /// impl for the differences with [`canonical_string`](Self::canonical_string).
+    fn ord_key(&self, s: &mut String) {
+        match self {
+            Self::Tuple(tuple) => {
+                s.push('(');
+                for (i, ty) in tuple.types.iter().enumerate() {
+                    if i > 0 {
+                        s.push(',');
+                    }
+                    ty.ord_key(s);
+                }
+                s.push(')');
+            }
+            Self::Array(array) => {
+                array.ty.ord_key(s);
+                array.write_brackets(s);
+            }
+            Self::Mapping(mapping) => {
+                s.push_str("mapping(");
+                mapping.key.ord_key(s);
+                s.push_str(" => ");
+                mapping.value.ord_key(s);
+                s.push(')');
+            }
+            Self::Function(function) => {
+                s.push_str("function");
+                params_ord_key(&function.arguments, s);
+                if let Some(returns) = &function.returns {
+                    s.push_str(" returns");
+                    params_ord_key(&returns.returns, s);
+                }
+            }
+            _ => self.canonical_string_raw(s),
+        }
+    }
+
+    /// Returns `true` if this type contains an array whose size is an
+    /// unevaluated expression.
+    pub(crate) fn has_size_exprs(&self) -> bool {
+        match self {
+            Self::Array(array) => array.has_size_expr() || array.ty.has_size_exprs(),
+            Self::Tuple(tuple) => tuple.types.iter().any(Self::has_size_exprs),
+            _ => false,
+        }
+    }
+
+    /// The default maximum nesting depth of types, see
+    /// [`parse_with_max_depth`](Self::parse_with_max_depth).
+    pub const DEFAULT_MAX_DEPTH: usize = 64;
+
+    /// Parses a type, erroring if it is nested deeper than `max_depth`.
+    ///
+    /// Every type counts as one level of nesting, including each array
+    /// dimension and the types inside tuples, mappings and function types:
+    /// `uint256[][]` and `(uint256[],)` have a depth of 3. This avoids
+    /// overflowing the stack when parsing untrusted input. The [`Parse`] implementation uses
+    /// [`DEFAULT_MAX_DEPTH`](Self::DEFAULT_MAX_DEPTH), unless it is called
+    /// from inside this function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use syn::parse::Parser;
+    /// use syn_solidity::Type;
+    ///
+    /// let parser = |input: syn::parse::ParseStream<'_>| Type::parse_with_max_depth(input, 3);
+    /// assert!(parser.parse_str("uint256[][]").is_ok());
+    /// assert!(parser.parse_str("uint256[][][]").is_err());
+    /// ```
+    pub fn parse_with_max_depth(input: ParseStream<'_>, max_depth: usize) -> Result<Self> {
+        let prev = MAX_DEPTH.with(|max| max.replace(max_depth));
+        let result = input.parse();
+        MAX_DEPTH.with(|max| max.set(prev));
+        result
+    }
+
     pub fn custom(ident: Ident) -> Self {
         Self::Custom(sol_path![ident])
     }
@@ -235,6 +492,8 @@ impl Type {
             "address" => Self::Address(span, None),
             "bool" => Self::Bool(span),
             "string" => Self::String(span),
+            // `byte` is an alias for `bytes1`, removed in Solidity 0.8.0
+            "byte" => Self::FixedBytes(span, NonZeroU16::new(1).unwrap()),
             s => {
                 if let Some(s) = s.strip_prefix("bytes") {
                     match parse_size(s, span)? {
@@ -261,6 +520,16 @@ impl Type {
                         }
                         Some(size) => Self::Uint(span, size),
                     }
+                } else if let Some(size) = s.strip_prefix("fixed") {
+                    match parse_fixed_size(size, span, "fixed")? {
+                        None => Self::custom(ident),
+                        Some(size) => Self::Fixed(span, size),
+                    }
+                } else if let Some(size) = s.strip_prefix("ufixed") {
+                    match parse_fixed_size(size, span, "ufixed")? {
+                        None => Self::custom(ident),
+                        Some(size) => Self::Ufixed(span, size),
+                    }
                 } else {
                     Self::custom(ident)
                 }
@@ -286,18 +555,61 @@ impl Type {
             Self::Bool(_)
                 | Self::Int(..)
                 | Self::Uint(..)
+                | Self::Fixed(..)
+                | Self::Ufixed(..)
                 | Self::FixedBytes(..)
                 | Self::Address(..)
                 | Self::Function(_)
         )
     }
 
+    /// Returns the number of bits of the values of this type, `None` if it is
+    /// not a value type.
+    ///
+    /// This is the `N` of `uintN`, `intN`, `fixedNxM` and `ufixedNxM`, `8 * N`
+    /// for `bytesN`, 160 for `address`, 192 for external function types, and
+    /// 1 for `bool`, which can only be 0 or 1. Internal function types have no
+    /// ABI representation and return `None`.
+    pub fn bit_width(&self) -> Option<usize> {
+        match self {
+            Self::Bool(_) => Some(1),
+            Self::Int(_, size) | Self::Uint(_, size) => {
+                Some(size.map_or(256, |size| size.get() as usize))
+            }
+            Self::Fixed(_, size) | Self::Ufixed(_, size) => {
+                Some(size.map_or(128, |(bits, _)| bits.get() as usize))
+            }
+            Self::FixedBytes(_, size) => Some(size.get() as usize * 8),
+            Self::Address(..) => Some(160),
+            Self::Function(f) if f.attributes.has_external() => Some(192),
+            _ => None,
+        }
+    }
+
+    /// Returns the selector of the signature of a function type, or `None` if
+    /// this is not a function type.
+    ///
+    /// Function types have no name, so the signature is only made of the
+    /// canonical argument types: `function (uint256) external` has the
+    /// signature `(uint256)`. Returns `None` as well if an argument is not
+    /// [ABI-encodable](Self::is_abi_encodable).
+    pub fn function_selector(&self) -> Option<[u8; 4]> {
+        match self {
+            Self::Function(function) if function.arguments.types().all(Self::is_abi_encodable) => {
+                Some(crate::utils::selector(function.arguments.signature(String::new())))
+            }
+            _ => None,
+        }
+    }
+
     /// Returns whether this type is dynamic according to ABI rules.
     pub fn is_abi_dynamic(&self) -> bool {
         match self {
             Self::Bool(_)
             | Self::Int(..)
             | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(..)
             | Self::Address(..)
             | Self::Function(_) => false,
@@ -312,15 +624,256 @@ impl Type {
         }
     }
 
+    /// Returns the JSON ABI `components` of this type: the descriptors of
+    /// the members of tuples, or of the tuple elements of arrays of tuples.
+    ///
+    /// Returns `None` for all other types, including custom types. See
+    /// [`abi_components_in`](Self::abi_components_in) to resolve structs.
+    pub fn abi_components(&self) -> Option<Vec<AbiParam>> {
+        AbiParam::new(None, self).components
+    }
+
+    /// Returns the JSON ABI `components` of this type like
+    /// [`abi_components`](Self::abi_components), also resolving custom types
+    /// that name a struct in `structs`. Their components are named after the
+    /// struct's fields.
+    pub fn abi_components_in(&self, structs: &StructRegistry) -> Option<Vec<AbiParam>> {
+        AbiParam::new_in(None, self, structs).components
+    }
+
+    /// Returns whether this type can be used in an ABI signature.
+    ///
+    /// This is `false` for mappings, internal function types, and arrays and
+    /// tuples containing them. External function types are encoded as
+    /// `function`, an address followed by a selector. Custom types are assumed
+    /// to be encodable.
+    pub fn is_abi_encodable(&self) -> bool {
+        match self {
+            Self::Mapping(_) => false,
+            Self::Function(function) => function.attributes.has_external(),
+            Self::Array(array) => array.ty.is_abi_encodable(),
+            Self::Tuple(tuple) => tuple.types.iter().all(Self::is_abi_encodable),
+            _ => true,
+        }
+    }
+
+    /// Returns the number of 32-byte words this type occupies in the head of
+    /// an ABI encoding.
+    ///
+    /// This is `1` for value types and dynamic types, which are encoded as an
+    /// offset into the tail. Static arrays and tuples occupy the sum of the
+    /// words of their elements. Arrays whose size is an unevaluated
+    /// expression are counted as having a single element.
+    ///
+    /// Returns `None` if the count overflows a `usize`, like for
+    /// `uint256[18446744073709551615][2]` on 64-bit targets.
+    pub fn head_word_count(&self) -> Option<usize> {
+        if self.is_abi_dynamic() {
+            return Some(1);
+        }
+        match self {
+            Self::Array(array) => {
+                array.size_as_usize().unwrap_or(1).checked_mul(array.ty.head_word_count()?)
+            }
+            Self::Tuple(tuple) => tuple
+                .types
+                .iter()
+                .try_fold(0usize, |sum, ty| sum.checked_add(ty.head_word_count()?)),
+            _ => Some(1),
+        }
+    }
+
+    /// Returns the ABI encoding of the zero value of this type, as defined by
+    /// `enc` in the [ABI specification][ref]: 32 zero bytes for value types,
+    /// a zero length for dynamic arrays, `bytes` and `string`, and the
+    /// encodings of the elements, in heads and tails, for fixed-size arrays
+    /// and tuples.
+    ///
+    /// Note that the encoding of a dynamic type as a function argument is
+    /// instead wrapped in a tuple, and is therefore preceded by its offset.
+    ///
+    /// [ref]: https://docs.soliditylang.org/en/latest/abi-spec.html#formal-specification-of-the-encoding
+    ///
+    /// Returns an error if the type contains a mapping or a custom type, which
+    /// cannot be encoded without being resolved, or an array whose size is an
+    /// expression, or if the encoding is too large to be allocated.
+    pub fn zero_abi_encoding(&self) -> crate::Result<Vec<u8>> {
+        self.try_zero_abi_encoding().map_err(crate::Error::Validation)
+    }
+
+    fn try_zero_abi_encoding(&self) -> Result<Vec<u8>> {
+        let too_large =
+            || Error::new(self.span(), format!("the ABI encoding of `{self}` is too large"));
+        let len = self.zero_abi_encoding_len()?.ok_or_else(too_large)?;
+        let mut out = Vec::new();
+        out.try_reserve_exact(len).map_err(|_| too_large())?;
+        self.zero_abi_encoding_raw(&mut out);
+        Ok(out)
+    }
+
+    /// Returns the length of [`zero_abi_encoding`](Self::zero_abi_encoding),
+    /// or `None` if it overflows a `usize`. Errors on types that cannot be
+    /// encoded.
+    fn zero_abi_encoding_len(&self) -> Result<Option<usize>> {
+        // a dynamic type in a sequence is preceded by its offset in the heads
+        fn element_len(ty: &Type) -> Result<Option<usize>> {
+            let len = ty.zero_abi_encoding_len()?;
+            Ok(if ty.is_abi_dynamic() { len.and_then(|len| len.checked_add(32)) } else { len })
+        }
+
+        match self {
+            Self::Mapping(_) => Err(Error::new(self.span(), "mappings cannot be ABI-encoded")),
+            Self::Custom(path) => {
+                let msg = format!("cannot ABI-encode unresolved custom type `{path}`");
+                Err(Error::new(self.span(), msg))
+            }
+            Self::Array(array) if array.has_size_expr() => {
+                let msg = format!("cannot ABI-encode array with unevaluated size `{self}`");
+                Err(Error::new(self.span(), msg))
+            }
+            Self::Array(array) => match array.size_as_usize() {
+                Some(len) => Ok(element_len(&array.ty)?.and_then(|elem| elem.checked_mul(len))),
+                None => Ok(Some(32)),
+            },
+            Self::Tuple(tuple) => {
+                let mut total = Some(0usize);
+                for ty in &tuple.types {
+                    let len = element_len(ty)?;
+                    total = total.zip(len).and_then(|(total, len)| total.checked_add(len));
+                }
+                Ok(total)
+            }
+            _ => Ok(Some(32)),
+        }
+    }
+
+    /// Encodes the zero value of this type into `out`. The type must have
+    /// been checked with [`zero_abi_encoding_len`](Self::zero_abi_encoding_len).
+    fn zero_abi_encoding_raw(&self, out: &mut Vec<u8>) {
+        match self {
+            Self::Array(array) => match array.size_as_usize() {
+                Some(len) => zero_abi_encode_sequence(std::iter::repeat(&*array.ty).take(len), out),
+                None => out.extend([0; 32]),
+            },
+            Self::Tuple(tuple) => zero_abi_encode_sequence(tuple.types.iter(), out),
+            // value types, and the zero length of `bytes` and `string`
+            _ => out.extend([0; 32]),
+        }
+    }
+
+    /// Returns the `alloy` Rust type that represents this type, spelled with
+    /// fully qualified paths into `alloy_sol_types`, such as
+    /// `::alloy_sol_types::private::U256`.
+    ///
+    /// This is a standalone approximation of the types generated by the
+    /// `sol!` macro, which can also be used without it. It does not take
+    /// the macro's crate path overrides into account:
+    ///
+    /// - integers map to the smallest Rust primitive that fits them, or to
+    ///   `I256`/`U256` if they are bigger than 128 bits;
+    /// - `bytes`, `bytesN`, `string` and `function` map to `Bytes`,
+    ///   `FixedBytes<N>`, `String` and `Function` respectively;
+    /// - fixed-size arrays map to `[T; N]` and dynamic ones to `Vec<T>`; size
+    ///   expressions are kept as-is;
+    /// - custom types map to their path as-is, so that user-defined Rust types
+    ///   with the same name are resolved.
+    ///
+    /// Mappings and fixed-point types have no Rust representation, and expand
+    /// to a `compile_error!` invocation instead.
+    pub fn to_rust_type(&self) -> TokenStream {
+        let span = self.span();
+        match self {
+            Self::Address(..) => quote_spanned! {span=> ::alloy_sol_types::private::Address },
+            Self::Bool(_) => quote_spanned! {span=> bool },
+            Self::String(_) => quote_spanned! {span=> ::alloy_sol_types::private::String },
+            Self::Bytes(_) => quote_spanned! {span=> ::alloy_sol_types::private::Bytes },
+            Self::FixedBytes(_, size) => {
+                let size = Literal::u16_unsuffixed(size.get());
+                quote_spanned! {span=> ::alloy_sol_types::private::FixedBytes<#size> }
+            }
+            // same as `alloy_sol_types::sol_data::{Int, Uint}::RustType`
+            Self::Int(_, size) | Self::Uint(_, size) => {
+                match (self, size.map_or(256, NonZeroU16::get)) {
+                    (Self::Int(..), 129..) => {
+                        quote_spanned! {span=> ::alloy_sol_types::private::I256 }
+                    }
+                    (_, 129..) => quote_spanned! {span=> ::alloy_sol_types::private::U256 },
+                    (ty, bits) => {
+                        let bits = bits.next_power_of_two().max(8);
+                        let name = format!(
+                            "{}{bits}",
+                            if matches!(ty, Self::Int(..)) { "i" } else { "u" }
+                        );
+                        Ident::new(&name, span).into_token_stream()
+                    }
+                }
+            }
+            Self::Tuple(tuple) => {
+                let types = tuple.types.iter().map(Self::to_rust_type);
+                let comma = (tuple.types.len() == 1).then_some(quote!(,));
+                quote_spanned! {span=> (#(#types),* #comma) }
+            }
+            Self::Array(array) => {
+                let ty = array.ty.to_rust_type();
+                match (array.size_as_usize(), array.size_tokens()) {
+                    (Some(size), _) => {
+                        let size = Literal::usize_unsuffixed(size);
+                        quote_spanned! {span=> [#ty; #size] }
+                    }
+                    (None, Some(size)) => quote_spanned! {span=> [#ty; #size] },
+                    (None, None) => quote_spanned! {span=> ::alloy_sol_types::private::Vec<#ty> },
+                }
+            }
+            Self::Custom(path) => {
+                let path = path.iter();
+                quote_spanned! {span=> #(#path)::* }
+            }
+            Self::Function(_) => quote_spanned! {span=> ::alloy_sol_types::private::Function },
+            Self::Mapping(_) => quote_spanned! {span=>
+                ::core::compile_error!("Mapping types are not supported here")
+            },
+            Self::Fixed(..) | Self::Ufixed(..) => quote_spanned! {span=>
+                ::core::compile_error!("Fixed-point types are not supported yet")
+            },
+        }
+    }
+
     /// Returns whether this type is a value type.
     ///
     /// These types' variables are always passed by value.
     ///
     /// See the [Solidity docs](https://docs.soliditylang.org/en/latest/types.html#value-types) for more information.
+    ///
+    /// Custom types are neither value nor reference types, as they must be
+    /// resolved first: enums, user-defined value types and contracts are value
+    /// types, while structs are reference types.
     pub const fn is_value_type(&self) -> bool {
         self.is_one_word()
     }
 
+    /// Returns whether this type is a reference type: `string`, `bytes`,
+    /// arrays, mappings, and tuples, which is how structs are represented
+    /// once resolved.
+    ///
+    /// This is the complement of [`is_value_type`](Self::is_value_type) for
+    /// all types except custom types, which are neither.
+    ///
+    /// See the [Solidity docs](https://docs.soliditylang.org/en/latest/types.html#reference-types) for more information.
+    pub const fn is_reference_type(&self) -> bool {
+        matches!(
+            self,
+            Self::String(_) | Self::Bytes(_) | Self::Array(_) | Self::Mapping(_) | Self::Tuple(_)
+        )
+    }
+
+    /// Returns `true` if this is an `address payable`.
+    ///
+    /// Note that `address` and `address payable` compare equal, since they
+    /// have the same ABI representation.
+    pub const fn is_address_payable(&self) -> bool {
+        matches!(self, Self::Address(_, Some(_)))
+    }
+
     pub const fn is_array(&self) -> bool {
         matches!(self, Self::Array(_))
     }
@@ -333,6 +886,62 @@ impl Type {
         matches!(self, Self::Custom(_))
     }
 
+    /// Recurses into this type and pushes all the custom type paths it
+    /// references to `paths`, in order of appearance.
+    pub(crate) fn custom_paths<'a>(&'a self, paths: &mut Vec<&'a SolPath>) {
+        match self {
+            Self::Custom(path) => paths.push(path),
+            Self::Array(a) => a.ty.custom_paths(paths),
+            Self::Tuple(t) => t.types.iter().for_each(|ty| ty.custom_paths(paths)),
+            Self::Function(f) => {
+                let returns = f.returns.as_ref().map(|ret| &ret.returns);
+                for arg in f.arguments.iter().chain(returns.into_iter().flatten()) {
+                    arg.ty.custom_paths(paths);
+                }
+            }
+            Self::Mapping(m) => {
+                m.key.custom_paths(paths);
+                m.value.custom_paths(paths);
+            }
+            _ => {}
+        }
+    }
+
+    /// Rewrites the elementary type aliases in this type to their canonical
+    /// form, recursively: `uint` to `uint256`, `int` to `int256`, `fixed` to
+    /// `fixed128x18` and `ufixed` to `ufixed128x18`.
+    ///
+    /// `byte` is always parsed as `bytes1`.
+    pub fn resolve_aliases(&mut self) {
+        match self {
+            Self::Int(_, size @ None) | Self::Uint(_, size @ None) => *size = NonZeroU16::new(256),
+            Self::Fixed(_, size @ None) | Self::Ufixed(_, size @ None) => {
+                *size = Some((NonZeroU16::new(128).unwrap(), 18))
+            }
+            Self::Array(a) => a.ty.resolve_aliases(),
+            Self::Tuple(t) => t.types.iter_mut().for_each(Self::resolve_aliases),
+            Self::Function(f) => {
+                let returns = f.returns.as_mut().map(|ret| &mut ret.returns);
+                for arg in f.arguments.iter_mut().chain(returns.into_iter().flatten()) {
+                    arg.ty.resolve_aliases();
+                }
+            }
+            Self::Mapping(m) => {
+                m.key.resolve_aliases();
+                m.value.resolve_aliases();
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns a copy of this type with its aliases resolved. See
+    /// [`resolve_aliases`](Self::resolve_aliases).
+    pub fn resolved(&self) -> Self {
+        let mut ty = self.clone();
+        ty.resolve_aliases();
+        ty
+    }
+
     /// Recurses into this type and returns whether it contains a custom type.
     pub fn has_custom(&self) -> bool {
         match self {
@@ -349,6 +958,8 @@ impl Type {
             Self::Bool(_)
             | Self::Int(..)
             | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(..)
             | Self::Address(..)
             | Self::String(_)
@@ -367,6 +978,8 @@ impl Type {
             Self::Bool(_)
             | Self::Int(..)
             | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(..)
             | Self::Address(..)
             | Self::Function(_)
@@ -375,7 +988,8 @@ impl Type {
         }
     }
 
-    /// Returns the inner type.
+    /// Returns the inner type, underneath all the array layers:
+    /// `uint256[][3]` -> `uint256`.
     pub fn peel_arrays(&self) -> &Self {
         let mut this = self;
         while let Self::Array(array) = this {
@@ -384,26 +998,105 @@ impl Type {
         this
     }
 
-    /// Returns the Solidity ABI name for this type. This is `tuple` for custom types, otherwise the
-    /// same as [`Display`](fmt::Display).
+    /// Returns the innermost non-array type. Alias of
+    /// [`peel_arrays`](Self::peel_arrays).
+    #[inline]
+    pub fn base_type(&self) -> &Self {
+        self.peel_arrays()
+    }
+
+    /// Returns the number of array layers around the
+    /// [base type](Self::base_type): `uint256[][3]` -> `2`.
+    pub fn array_depth(&self) -> usize {
+        let mut this = self;
+        let mut depth = 0;
+        while let Self::Array(array) = this {
+            this = &array.ty;
+            depth += 1;
+        }
+        depth
+    }
+
+    /// Returns the Solidity ABI name for this type. This is `tuple` for custom types, `address`
+    /// for `address payable`, `function` for function types, otherwise the same as
+    /// [`Display`](fmt::Display).
     pub fn abi_name(&self) -> String {
         let mut s = String::new();
         self.abi_name_raw(&mut s);
         s
     }
 
-    /// Returns the Solidity ABI name for this type. This is `tuple` for custom types, otherwise the
-    /// same as [`Display`](fmt::Display).
+    /// Returns the Solidity ABI name for this type.
+    ///
+    /// See [`abi_name`](Self::abi_name) for more details.
     pub fn abi_name_raw(&self, s: &mut String) {
         match self {
             Self::Custom(_) => s.push_str("tuple"),
+            Self::Address(..) => s.push_str("address"),
+            Self::Function(_) => s.push_str("function"),
             Self::Array(array) => {
                 array.ty.abi_name_raw(s);
-                if let Some(size) = array.size() {
-                    write!(s, "[{size}]").unwrap();
-                } else {
-                    s.push_str("[]");
+                array.write_brackets(s);
+            }
+            _ => write!(s, "{self}").unwrap(),
+        }
+    }
+
+    /// Returns a value that formats this type in its canonical form, as
+    /// [`canonical_string`](Self::canonical_string) does.
+    ///
+    /// [`Display`](fmt::Display) instead reproduces the source spelling.
+    pub fn display_canonical(&self) -> impl fmt::Display + '_ {
+        DisplayCanonical(self)
+    }
+
+    /// Returns the canonical Solidity ABI spelling of this type, as used in
+    /// selector preimages.
+    ///
+    /// This differs from [`Display`](fmt::Display) by expanding aliases, like
+    /// `uint` to `uint256`, by omitting the trailing comma in single element
+    /// tuples, and by spelling `address payable` as `address` and function
+    /// types as `function`. Mappings drop their key and value names. Custom
+    /// types are not resolved, and array size expressions are kept as-is:
+    /// `uint256[N]`.
+    pub fn canonical_string(&self) -> String {
+        let mut s = String::new();
+        self.canonical_string_raw(&mut s);
+        s
+    }
+
+    /// Returns the canonical Solidity ABI spelling of this type, as used in
+    /// selector preimages.
+    ///
+    /// See [`canonical_string`](Self::canonical_string) for more details.
+    pub fn canonical_string_raw(&self, s: &mut String) {
+        match self {
+            Self::Int(_, None) => s.push_str("int256"),
+            Self::Uint(_, None) => s.push_str("uint256"),
+            Self::Fixed(_, None) => s.push_str("fixed128x18"),
+            Self::Ufixed(_, None) => s.push_str("ufixed128x18"),
+            Self::Address(..) => s.push_str("address"),
+            Self::Function(_) => s.push_str("function"),
+            Self::Array(array) => {
+                array.ty.canonical_string_raw(s);
+                array.write_brackets(s);
+            }
+            Self::Tuple(tuple) => {
+                s.push('(');
+                for (i, ty) in tuple.types.iter().enumerate() {
+                    if i > 0 {
+                        s.push(',');
+                    }
+                    ty.canonical_string_raw(s);
                 }
+                s.push(')');
+            }
+            Self::Mapping(mapping) => {
+                s.push_str("mapping(");
+                mapping.key.canonical_string_raw(s);
+                s.push_str(" => ");
+                mapping.value.canonical_string_raw(s);
+                s.push(')');
             }
             _ => write!(s, "{self}").unwrap(),
         }
@@ -483,6 +1176,80 @@ fn write_opt(f: &mut fmt::Formatter<'_>, name: &str, size: Option<NonZeroU16>) -
 
 // None => Custom
 // Some(size) => size
+fn write_fixed(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    size: Option<(NonZeroU16, u8)>,
+) -> fmt::Result {
+    f.write_str(name)?;
+    if let Some((bits, decimals)) = size {
+        write!(f, "{bits}x{decimals}")?;
+    }
+    Ok(())
+}
+
+/// Parses the `<bits>x<decimals>` suffix of a fixed point type.
+///
+/// Returns `None` if `s` is not a fixed point suffix, and `Some(None)` if it
+/// is empty.
+fn parse_fixed_size(s: &str, span: Span, name: &str) -> Result<Option<Option<(NonZeroU16, u8)>>> {
+    if s.is_empty() {
+        return Ok(Some(None));
+    }
+    let is_number = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    let Some((bits, decimals)) = s.split_once('x').filter(|(m, n)| is_number(m) && is_number(n))
+    else {
+        return Ok(None);
+    };
+
+    let bits = bits.parse::<NonZeroU16>().ok().filter(|m| m.get() <= 256 && m.get() % 8 == 0);
+    let Some(bits) = bits else {
+        let msg = format!("{name}MxN bits must be a multiple of 8 up to 256");
+        return Err(Error::new(span, msg));
+    };
+    let Some(decimals) = decimals.parse::<u8>().ok().filter(|&n| n <= 80) else {
+        return Err(Error::new(span, format!("{name}MxN decimals must be at most 80")));
+    };
+    Ok(Some(Some((bits, decimals))))
+}
+
+thread_local! {
+    /// The nesting depth of the type currently being parsed.
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+    /// The maximum nesting depth, see [`Type::parse_with_max_depth`].
+    static MAX_DEPTH: Cell<usize> = const { Cell::new(Type::DEFAULT_MAX_DEPTH) };
+}
+
+/// Tracks the nesting depth of [`Type::parse`], restoring it when dropped.
+struct DepthGuard(usize);
+
+impl DepthGuard {
+    fn enter(input: ParseStream<'_>) -> Result<Self> {
+        let mut this = Self(0);
+        this.deepen(input)?;
+        Ok(this)
+    }
+
+    fn deepen(&mut self, input: ParseStream<'_>) -> Result<()> {
+        let depth = DEPTH.with(|depth| {
+            depth.set(depth.get() + 1);
+            depth.get()
+        });
+        self.0 += 1;
+        let max = MAX_DEPTH.with(Cell::get);
+        if depth > max {
+            return Err(input.error(format!("type nesting too deep, the maximum is {max}")));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for DepthGuard {
+    fn drop(&mut self) {
+        DEPTH.with(|depth| depth.set(depth.get() - self.0));
+    }
+}
+
 fn parse_size(s: &str, span: Span) -> Result<Option<Option<NonZeroU16>>> {
     let opt = match s.parse::<NonZeroU16>() {
         Ok(size) => Some(Some(size)),
@@ -497,3 +1264,701 @@ fn parse_size(s: &str, span: Span) -> Result<Option<Option<NonZeroU16>>> {
     };
     Ok(opt)
 }
+
+/// Encodes the zero values of `types` as a sequence of heads, followed by the
+/// tails of the dynamic types.
+fn zero_abi_encode_sequence<'a>(types: impl Iterator<Item = &'a Type> + Clone, out: &mut Vec<u8>) {
+    // the heads are part of the encoding, whose length was checked not to overflow
+    let head_len = types.clone().map(|ty| ty.head_word_count().unwrap()).sum::<usize>() * 32;
+    let mut tail = Vec::new();
+    for ty in types {
+        if ty.is_abi_dynamic() {
+            let mut offset = [0; 32];
+            offset[24..].copy_from_slice(&((head_len + tail.len()) as u64).to_be_bytes());
+            out.extend(offset);
+            ty.zero_abi_encoding_raw(&mut tail);
+        } else {
+            ty.zero_abi_encoding_raw(out);
+        }
+    }
+    out.extend(tail);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn from_str() {
+        let ty: Type = "(uint256,bytes32[2])[]".parse().unwrap();
+        assert_eq!(ty.to_string(), "(uint256,bytes32[2])[]");
+        assert_eq!(Type::try_from("(uint256, bytes32[2])[]").unwrap(), ty);
+        assert_eq!("address".parse::<Type>().unwrap(), Type::Address(Span::call_site(), None));
+
+        let e = Type::try_from("uint999").unwrap_err();
+        assert!(matches!(e, crate::Error::Parse(_)), "{e:?}");
+        assert_eq!(e.to_string(), "uintX must be a multiple of 8 up to 256");
+        "uint256 x".parse::<Type>().unwrap_err();
+    }
+
+    #[test]
+    fn mappings() {
+        let nested: Type = syn::parse_str("mapping(address => mapping(uint256 => bool))").unwrap();
+        let Type::Mapping(map) = &nested else { panic!("{nested:?}") };
+        assert_eq!(*map.key, Type::Address(Span::call_site(), None));
+        assert!(matches!(*map.value, Type::Mapping(_)));
+        assert_eq!(nested.to_string(), "mapping(address => mapping(uint256 => bool))");
+
+        let named: Type = syn::parse_str("mapping(address owner => uint256 balance)").unwrap();
+        let Type::Mapping(map) = &named else { panic!("{named:?}") };
+        assert_eq!(map.key_name.as_ref().unwrap(), "owner");
+        assert_eq!(map.value_name.as_ref().unwrap(), "balance");
+        assert_eq!(named.to_string(), "mapping(address owner => uint256 balance)");
+
+        for ty in [nested, named] {
+            let reparsed: Type = syn::parse_str(&ty.to_string()).unwrap();
+            assert_eq!(reparsed.to_string(), ty.to_string());
+        }
+    }
+
+    #[test]
+    fn elementary_sizes() {
+        for (s, err) in [
+            ("uint255", "uintX must be a multiple of 8 up to 256"),
+            ("int264", "intX must be a multiple of 8 up to 256"),
+            ("bytes33", "fixed bytes range is 1-32"),
+        ] {
+            assert_eq!(syn::parse_str::<Type>(s).unwrap_err().to_string(), err, "{s}");
+        }
+
+        let uint: Type = syn::parse_str("uint").unwrap();
+        assert_eq!(uint, Type::Uint(Span::call_site(), None));
+        assert_eq!(uint.canonical_string(), "uint256");
+        let bytes32: Type = syn::parse_str("bytes32").unwrap();
+        assert_eq!(bytes32, Type::FixedBytes(Span::call_site(), NonZeroU16::new(32).unwrap()));
+    }
+
+    #[test]
+    fn abi_components() {
+        let param = |ty: &str, internal_type: &str, components| AbiParam {
+            name: String::new(),
+            ty: ty.into(),
+            internal_type: internal_type.into(),
+            components,
+        };
+
+        let flat: Type = syn::parse_str("(uint, bool)").unwrap();
+        let expected = vec![param("uint256", "uint256", None), param("bool", "bool", None)];
+        assert_eq!(flat.abi_components(), Some(expected.clone()));
+
+        let array: Type = syn::parse_str("(uint256, (bool, address))[2][]").unwrap();
+        let inner = vec![param("bool", "bool", None), param("address", "address", None)];
+        assert_eq!(
+            array.abi_components(),
+            Some(vec![param("uint256", "uint256", None), param("tuple", "tuple", Some(inner))])
+        );
+
+        for s in ["uint256", "bytes[]", "Point"] {
+            let ty: Type = syn::parse_str(s).unwrap();
+            assert_eq!(ty.abi_components(), None, "{s}");
+        }
+
+        let file: crate::File =
+            syn::parse_str("struct Point { uint256 x; uint256 y; } struct Line { Point[2] ends; }")
+                .unwrap();
+        let structs = StructRegistry::from_items(&file.items);
+        let line: Type = syn::parse_str("Line").unwrap();
+        let components = line.abi_components_in(&structs).unwrap();
+        assert_eq!(components.len(), 1);
+        let ends = &components[0];
+        assert_eq!((ends.name.as_str(), ends.ty.as_str()), ("ends", "tuple[2]"));
+        assert_eq!(ends.internal_type, "struct Point[2]");
+        let names = ends.components.as_ref().unwrap().iter().map(|c| c.name.as_str());
+        assert_eq!(names.collect::<Vec<_>>(), ["x", "y"]);
+        assert_eq!(flat.abi_components_in(&structs), Some(expected));
+
+        let file: crate::File =
+            syn::parse_str("struct Node { uint v; Node[] kids; } struct Tree { Node root; }")
+                .unwrap();
+        let structs = StructRegistry::from_items(&file.items);
+        let node: Type = syn::parse_str("Node").unwrap();
+        let components = node.abi_components_in(&structs).unwrap();
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[1].ty, "Node[]");
+        assert_eq!(components[1].components, None);
+        let tree: Type = syn::parse_str("Tree").unwrap();
+        let root = &tree.abi_components_in(&structs).unwrap()[0];
+        assert_eq!(root.internal_type, "struct Node");
+        assert_eq!(root.components.as_ref().unwrap()[1].components, None);
+    }
+
+    #[test]
+    fn value_and_reference_types() {
+        for (s, value) in [
+            ("uint256", true),
+            ("int8", true),
+            ("bool", true),
+            ("address", true),
+            ("address payable", true),
+            ("bytes1", true),
+            ("bytes32", true),
+            ("fixed128x18", true),
+            ("function () external", true),
+            ("bytes", false),
+            ("string", false),
+            ("uint256[]", false),
+            ("bytes32[2]", false),
+            ("(uint256,bool)", false),
+            ("mapping(address => uint256)", false),
+        ] {
+            let ty: Type = syn::parse_str(s).unwrap();
+            assert_eq!(ty.is_value_type(), value, "{s}");
+            assert_eq!(ty.is_reference_type(), !value, "{s}");
+        }
+
+        let custom: Type = syn::parse_str("Custom").unwrap();
+        assert!(!custom.is_value_type());
+        assert!(!custom.is_reference_type());
+    }
+
+    #[test]
+    fn nesting_depth() {
+        let msg = "type nesting too deep, the maximum is 64";
+        for deep in [
+            format!("uint256{}", "[]".repeat(200)),
+            format!("{}uint256{}", "(".repeat(200), ",)".repeat(200)),
+            format!("{}bool{}", "mapping(uint => ".repeat(200), ")".repeat(200)),
+        ] {
+            assert_eq!(syn::parse_str::<Type>(&deep).unwrap_err().to_string(), msg);
+        }
+
+        // the outermost type counts as one level
+        let max = format!("uint256{}", "[]".repeat(Type::DEFAULT_MAX_DEPTH - 1));
+        assert_eq!(syn::parse_str::<Type>(&max).unwrap().array_depth(), 63);
+        let e = syn::parse_str::<Type>(&format!("({max}, bool)")).unwrap_err();
+        assert_eq!(e.to_string(), msg);
+        // the depth is reset after an error
+        syn::parse_str::<Type>(&max).unwrap();
+
+        use syn::parse::Parser;
+        let parser = |input: ParseStream<'_>| Type::parse_with_max_depth(input, 3);
+        parser.parse_str("(uint256[], bool)").unwrap();
+        let e = parser.parse_str("(uint256[][], bool)").unwrap_err();
+        assert_eq!(e.to_string(), "type nesting too deep, the maximum is 3");
+        syn::parse_str::<Type>(&max).unwrap();
+    }
+
+    #[test]
+    fn debug() {
+        let ty: Type = syn::parse_str("uint256[]").unwrap();
+        assert_eq!(format!("{ty:?}"), r#"Type("uint256[]")"#);
+        assert_eq!(format!("{:?}", ty.debug_verbose()), "Type::Array(Type::Uint(Some(256)), None)");
+
+        let ty: Type = syn::parse_str("(uint, address payable)[2]").unwrap();
+        assert_eq!(format!("{ty:?}"), r#"Type("(uint256,address)[2]")"#);
+        assert_eq!(
+            format!("{:?}", ty.debug_verbose()),
+            "Type::Array(Type::Tuple(Type::Uint(None), Type::AddressPayable), Some(2))"
+        );
+
+        let ty: Type = syn::parse_str("mapping(address => bool[N])").unwrap();
+        assert_eq!(format!("{ty:?}"), r#"Type("mapping(address => bool[N])")"#);
+        let ty: Type = syn::parse_str("(bool,)[N + 1]").unwrap();
+        assert_eq!(format!("{ty:?}"), r#"Type("(bool,)[N + 1]")"#);
+    }
+
+    #[test]
+    fn size_exprs() {
+        let ty = crate::parse_type("uint256[N]").unwrap();
+        assert_eq!(ty.canonical_string(), "uint256[N]");
+        assert_eq!(ty.abi_name(), "uint256[N]");
+        assert_eq!(ty.head_word_count(), Some(1));
+        let ty = crate::parse_type("(uint, bool[N + 1])[2]").unwrap();
+        assert_eq!(ty.canonical_string(), "(uint256,bool[N + 1])[2]");
+        assert!(!ty.is_abi_dynamic());
+
+        let f = crate::parse_function("function f(uint256[N] x);").unwrap();
+        let e = f.signature().unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "the array size of `uint256[N]` must be a literal in an ABI signature"
+        );
+        f.selector().unwrap_err();
+        f.full_signature().unwrap_err();
+        assert!(f == "f(uint256[N])");
+        #[cfg(feature = "json")]
+        assert_eq!(f.to_abi_json().unwrap()["inputs"][0]["type"], "uint256[N]");
+    }
+
+    #[test]
+    fn function_selector() {
+        let ty: Type =
+            syn::parse_str("function (uint256, address) external returns (bool)").unwrap();
+        let f: crate::ItemFunction =
+            syn::parse_str("function f(uint256 a, address b) external returns (bool);").unwrap();
+        let preimage = f.signature().unwrap();
+        let expected = crate::utils::selector(preimage.strip_prefix('f').unwrap());
+        assert_eq!(ty.function_selector(), Some(expected));
+
+        let ty: Type = syn::parse_str("function () external").unwrap();
+        assert_eq!(ty.function_selector(), Some(crate::utils::selector("()")));
+        let ty: Type = syn::parse_str("function (function () external) external").unwrap();
+        assert_eq!(ty.function_selector(), Some(crate::utils::selector("(function)")));
+        let ty: Type = syn::parse_str("function (function () internal) external").unwrap();
+        assert_eq!(ty.function_selector(), None);
+        assert_eq!(syn::parse_str::<Type>("uint256").unwrap().function_selector(), None);
+    }
+
+    #[test]
+    fn ord() {
+        let mut types: Vec<Type> = [
+            "uint256[]",
+            "bool",
+            "uint8",
+            "(uint, address)",
+            "address payable",
+            "bytes32",
+            "uint",
+            "mapping(address => uint)",
+            "IERC20",
+            "string",
+        ]
+        .into_iter()
+        .map(|s| syn::parse_str(s).unwrap())
+        .collect();
+        types.sort();
+        let sorted = types.iter().map(Type::canonical_string).collect::<Vec<_>>();
+        let mut expected = sorted.clone();
+        expected.sort();
+        assert_eq!(sorted, expected);
+        assert_eq!(
+            sorted,
+            [
+                "(uint256,address)",
+                "IERC20",
+                "address",
+                "bool",
+                "bytes32",
+                "mapping(address => uint256)",
+                "string",
+                "uint256",
+                "uint256[]",
+                "uint8",
+            ]
+        );
+
+        let parse = |s: &str| syn::parse_str::<Type>(s).unwrap();
+        assert_eq!(parse("uint").cmp(&parse("uint256")), Ordering::Equal);
+        assert_eq!(parse("address").cmp(&parse("address payable")), Ordering::Equal);
+        let (a, b) = (parse("mapping(address a => bool)"), parse("mapping(address => bool b)"));
+        assert_eq!(a, b);
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+        let (a, b) = (parse("function (uint) external"), parse("function (bool) external"));
+        assert_ne!(a, b);
+        assert_eq!(a.cmp(&b), Ordering::Greater);
+        assert_eq!(parse("bool[N]").cmp(&parse("bool[M]")), Ordering::Greater);
+    }
+
+    #[test]
+    fn ord_consistent_with_eq() {
+        let types: Vec<Type> = [
+            "uint",
+            "uint256",
+            "uint200",
+            "int",
+            "int256",
+            "fixed",
+            "fixed128x18",
+            "byte",
+            "bytes1",
+            "address",
+            "address payable",
+            "(uint, int)",
+            "(uint256, int256)",
+            "uint[2][]",
+            "uint256[2][]",
+            "uint[N]",
+            "mapping(uint => bool)",
+            "mapping(uint256 k => bool v)",
+            "function (uint) external",
+            "function (uint256) external",
+            "function (uint200) external",
+            "function (uint a) external",
+            "function (uint,) external",
+            "function (uint) external returns (bool)",
+            "function (uint256) external returns (bool)",
+            "function (uint256) external returns ()",
+        ]
+        .into_iter()
+        .map(|s| crate::parse_type(s).unwrap())
+        .collect();
+
+        for a in &types {
+            for b in &types {
+                assert_eq!(a == b, a.cmp(b) == Ordering::Equal, "{a} <=> {b}");
+                assert_eq!(a.cmp(b), b.cmp(a).reverse(), "{a} <=> {b}");
+                for c in &types {
+                    if a <= b && b <= c {
+                        assert!(a <= c, "{a} <= {b} <= {c}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bit_width() {
+        for (s, bits) in [
+            ("uint8", Some(8)),
+            ("uint", Some(256)),
+            ("int128", Some(128)),
+            ("bytes4", Some(32)),
+            ("bytes32", Some(256)),
+            ("address", Some(160)),
+            ("address payable", Some(160)),
+            ("bool", Some(1)),
+            ("ufixed64x10", Some(64)),
+            ("function () external", Some(192)),
+            ("function () internal", None),
+            ("function ()", None),
+            ("string", None),
+            ("bytes", None),
+            ("uint8[2]", None),
+            ("(uint8,)", None),
+            ("Custom", None),
+        ] {
+            let ty: Type = syn::parse_str(s).unwrap();
+            assert_eq!(ty.bit_width(), bits, "{s}");
+        }
+    }
+
+    #[test]
+    fn bytes_boundaries() {
+        let bytes: Type = syn::parse_str("bytes").unwrap();
+        assert_eq!(bytes, Type::Bytes(Span::call_site()));
+        assert!(bytes.is_abi_dynamic());
+
+        for size in [1, 32] {
+            let ty: Type = syn::parse_str(&format!("bytes{size}")).unwrap();
+            let Type::FixedBytes(_, width) = ty else { panic!("{ty:?}") };
+            assert_eq!(width.get(), size);
+            assert!(!ty.is_abi_dynamic());
+        }
+
+        let e = syn::parse_str::<Type>("bytes0").unwrap_err();
+        assert_eq!(e.to_string(), "invalid size: number would be zero for non-zero type");
+        let e = syn::parse_str::<Type>("bytes33").unwrap_err();
+        assert_eq!(e.to_string(), "fixed bytes range is 1-32");
+
+        // not elementary types
+        for s in ["bytes_", "bytesX"] {
+            let ty: Type = syn::parse_str(s).unwrap();
+            assert!(ty.is_custom(), "{s}: {ty:?}");
+        }
+    }
+
+    #[test]
+    fn fixed_point() {
+        let ufixed: Type = syn::parse_str("ufixed").unwrap();
+        assert_eq!(ufixed, Type::Ufixed(Span::call_site(), None));
+        assert_eq!(ufixed.to_string(), "ufixed");
+        assert_eq!(ufixed.canonical_string(), "ufixed128x18");
+
+        let fixed: Type = syn::parse_str("fixed128x18").unwrap();
+        assert_eq!(
+            fixed,
+            Type::Fixed(Span::call_site(), Some((NonZeroU16::new(128).unwrap(), 18)))
+        );
+        assert_eq!(fixed.to_string(), "fixed128x18");
+        assert!(!fixed.is_abi_dynamic());
+
+        for (s, err) in [
+            ("fixed8x99", "fixedMxN decimals must be at most 80"),
+            ("ufixed7x2", "ufixedMxN bits must be a multiple of 8 up to 256"),
+            ("fixed264x0", "fixedMxN bits must be a multiple of 8 up to 256"),
+        ] {
+            assert_eq!(syn::parse_str::<Type>(s).unwrap_err().to_string(), err, "{s}");
+        }
+
+        let custom: Type = syn::parse_str("fixedPoint").unwrap();
+        assert!(custom.is_custom());
+    }
+
+    #[test]
+    fn eq_hash() {
+        let types: std::collections::HashSet<Type> = ["uint", "uint256", " uint256 "]
+            .into_iter()
+            .map(|s| syn::parse_str(s).unwrap())
+            .collect();
+        assert_eq!(types.len(), 1);
+
+        let a: Type = syn::parse_str("(int, fixed)[2]").unwrap();
+        let b: Type = syn::parse_str("(int256,fixed128x18)[2]").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, syn::parse_str::<Type>("(int8,fixed128x18)[2]").unwrap());
+    }
+
+    #[test]
+    fn rust_types() {
+        for (s, rust) in [
+            ("address payable", "Address"),
+            ("bool", "bool"),
+            ("string", "String"),
+            ("bytes", "Bytes"),
+            ("bytes4", "FixedBytes < 4 >"),
+            ("uint24", "u32"),
+            ("int", "I256"),
+            ("uint160[2]", "[U256 ; 2]"),
+            ("(bool,string[])", "(bool , Vec < String >)"),
+            ("(address,)", "(Address ,)"),
+            ("function () external", "Function"),
+            ("IERC20.Info[]", "Vec < IERC20 :: Info >"),
+            ("bool[N]", "[bool ; N]"),
+        ] {
+            let ty: Type = syn::parse_str(s).unwrap();
+            let tokens = ty.to_rust_type().to_string();
+            assert_eq!(tokens.replace(":: alloy_sol_types :: private :: ", ""), rust, "{s}");
+        }
+    }
+
+    #[test]
+    fn address_payable() {
+        let address: Type = syn::parse_str("address").unwrap();
+        let payable: Type = syn::parse_str("address payable").unwrap();
+        assert!(!address.is_address_payable());
+        assert!(payable.is_address_payable());
+        assert_eq!(address.canonical_string(), payable.canonical_string());
+        assert_ne!(address.to_string(), payable.to_string());
+
+        let f: crate::ItemFunction =
+            syn::parse_str("function f(address payable to, address from);").unwrap();
+        assert_eq!(f.signature().unwrap(), "f(address,address)");
+        assert_eq!(f.parameters.to_string(), "address payable to, address from");
+    }
+
+    #[test]
+    fn display_round_trip() {
+        for s in [
+            "address payable",
+            "uint",
+            "(uint,(address payable,bool)[],string)[2][]",
+            "mapping(address => mapping(uint256 => bool[]))",
+            "function (uint256, bool) external view returns (bytes32)",
+            "fixed128x18",
+            "IERC20.Info[3]",
+        ] {
+            let ty: Type = syn::parse_str(s).unwrap();
+            assert_eq!(ty.to_string(), s);
+            assert_eq!(syn::parse_str::<Type>(&ty.to_string()).unwrap(), ty);
+        }
+
+        let ty: Type = syn::parse_str("(uint, address payable, function () external)").unwrap();
+        assert_eq!(ty.to_string(), "(uint,address payable,function () external)");
+        assert_eq!(ty.display_canonical().to_string(), "(uint256,address,function)");
+
+        let ty: Type =
+            syn::parse_str("mapping(uint owner => mapping(int => uint[]) data)").unwrap();
+        assert_eq!(ty.to_string(), "mapping(uint owner => mapping(int => uint[]) data)");
+        assert_eq!(ty.canonical_string(), "mapping(uint256 => mapping(int256 => uint256[]))");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let ty: Type = syn::parse_str("(uint, (address payable, bytes4[2])[], string)").unwrap();
+        let json = serde_json::to_string(&ty).unwrap();
+        assert_eq!(json, r#""(uint256,(address,bytes4[2])[],string)""#);
+        let back: Type = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, ty);
+        assert_eq!(back.canonical_string(), ty.canonical_string());
+
+        serde_json::from_str::<Type>(r#""uint7""#).unwrap_err();
+    }
+
+    #[test]
+    fn resolve_aliases() {
+        for (s, resolved) in [
+            ("mapping(uint => int[])", "mapping(uint256 => int256[])"),
+            ("(fixed, ufixed[2], byte)", "(fixed128x18,ufixed128x18[2],bytes1)"),
+            (
+                "function (uint) external returns (int)",
+                "function (uint256) external returns (int256)",
+            ),
+            ("uint8", "uint8"),
+        ] {
+            let mut ty: Type = syn::parse_str(s).unwrap();
+            assert_eq!(ty.resolved().to_string(), resolved, "{s}");
+            assert_eq!(ty.resolved(), ty, "{s}");
+            ty.resolve_aliases();
+            assert_eq!(ty.to_string(), resolved, "{s}");
+        }
+    }
+
+    #[test]
+    fn base_type() {
+        for (s, base, depth) in [
+            ("uint256", "uint256", 0),
+            ("(bool, uint8[])", "(bool,uint8[])", 0),
+            ("address[]", "address", 1),
+            ("uint256[][3]", "uint256", 2),
+            ("(bool,)[2][][4]", "(bool,)", 3),
+        ] {
+            let ty: Type = syn::parse_str(s).unwrap();
+            assert_eq!(ty.base_type().to_string(), base, "{s}");
+            assert_eq!(ty.array_depth(), depth, "{s}");
+        }
+    }
+
+    #[test]
+    fn canonical_strings() {
+        for (s, canonical) in [
+            ("(uint, (address, bool)[])", "(uint256,(address,bool)[])"),
+            ("uint8[2][3]", "uint8[2][3]"),
+            ("int[][4]", "int256[][4]"),
+            ("(bytes,)", "(bytes)"),
+        ] {
+            let ty: Type = syn::parse_str(s).unwrap();
+            assert_eq!(ty.canonical_string(), canonical, "{s}");
+        }
+    }
+
+    #[test]
+    fn array_tuple_nesting() {
+        for (s, canonical, dynamic) in [
+            ("(uint256, address)[]", "(uint256,address)[]", true),
+            ("(uint256, address)[2]", "(uint256,address)[2]", false),
+            ("uint256[3][]", "uint256[3][]", true),
+            ("(uint256[], bool[2])[3]", "(uint256[],bool[2])[3]", true),
+        ] {
+            let ty: Type = syn::parse_str(s).unwrap();
+            assert_eq!(ty.canonical_string(), canonical, "{s}");
+            assert_eq!(ty.is_abi_dynamic(), dynamic, "{s}");
+        }
+
+        // `uint256[3][]` is a dynamic array of `uint256[3]`
+        let Type::Array(outer) = syn::parse_str("uint256[3][]").unwrap() else { unreachable!() };
+        assert_eq!(outer.size_as_usize(), None);
+        assert!(matches!(&*outer.ty, Type::Array(inner) if inner.size() == Some(3)));
+
+        let Type::Array(array) = syn::parse_str("(uint256, address)[2]").unwrap() else {
+            unreachable!()
+        };
+        assert!(matches!(&*array.ty, Type::Tuple(tuple) if tuple.types.len() == 2));
+    }
+
+    #[test]
+    fn zero_abi_encoding() {
+        let words = |s: &str| {
+            let ty: Type = syn::parse_str(s).unwrap();
+            let encoded = ty.zero_abi_encoding().unwrap();
+            assert_eq!(encoded.len() % 32, 0, "{s}");
+            encoded.chunks(32).map(|word| word[31] as usize).collect::<Vec<_>>()
+        };
+
+        assert_eq!(words("uint256"), [0]);
+        assert_eq!(words("uint256[2]"), [0, 0]);
+        assert_eq!(words("string"), [0]);
+        assert_eq!(words("bytes32[]"), [0]);
+        assert_eq!(words("(bool, address, bytes4)"), [0, 0, 0]);
+        // heads: offset of the string, the static array; tail: the string length
+        assert_eq!(words("(string, uint8[2])"), [0x60, 0, 0, 0]);
+        // a static array of dynamic types is encoded like a tuple
+        assert_eq!(words("bytes[2]"), [0x40, 0x60, 0, 0]);
+        assert_eq!(words("(uint, (string, bool))"), [0, 0x40, 0x40, 0, 0]);
+
+        let ty: Type = syn::parse_str("uint256").unwrap();
+        assert_eq!(ty.zero_abi_encoding().unwrap(), [0; 32]);
+
+        let max = usize::MAX;
+        for (s, msg) in [
+            ("mapping(uint => bool)", "mappings cannot be ABI-encoded".to_string()),
+            ("(bool, Foo)", "cannot ABI-encode unresolved custom type `Foo`".to_string()),
+            ("uint8[N][2]", "cannot ABI-encode array with unevaluated size `uint8[N]`".to_string()),
+            (
+                &format!("uint256[{max}][2]"),
+                format!("the ABI encoding of `uint256[{max}][2]` is too large"),
+            ),
+            (
+                &format!("(bool, string[{max}])"),
+                format!("the ABI encoding of `(bool,string[{max}])` is too large"),
+            ),
+        ] {
+            let ty: Type = syn::parse_str(s).unwrap();
+            let e = ty.zero_abi_encoding().unwrap_err();
+            assert!(matches!(e, crate::Error::Validation(_)), "{e:?}");
+            assert_eq!(e.to_string(), msg, "{s}");
+        }
+    }
+
+    #[test]
+    fn abi_dynamic() {
+        for (s, dynamic) in [
+            ("uint256", false),
+            ("address payable", false),
+            ("bytes32", false),
+            ("function () external", false),
+            ("bytes", true),
+            ("string", true),
+            ("uint256[3]", false),
+            ("uint256[]", true),
+            ("string[2]", true),
+            ("(uint256,bool)", false),
+            ("(uint256,string)", true),
+            ("(uint256,(bool,bytes)[2])[3]", true),
+        ] {
+            let ty: Type = syn::parse_str(s).unwrap();
+            assert_eq!(ty.is_abi_dynamic(), dynamic, "{s}");
+        }
+    }
+
+    #[test]
+    fn head_word_count() {
+        for (s, words) in [
+            ("uint256", 1),
+            ("string", 1),
+            ("uint256[]", 1),
+            ("uint256[3]", 3),
+            ("(uint8,uint8)", 2),
+            ("(uint8,string)", 1),
+            ("(bool,bytes32[2])[3]", 9),
+        ] {
+            let ty: Type = syn::parse_str(s).unwrap();
+            assert_eq!(ty.head_word_count(), Some(words), "{s}");
+        }
+
+        let max = usize::MAX;
+        for s in [format!("uint256[{max}][2]"), format!("(uint256[{max}],bool)")] {
+            let ty: Type = syn::parse_str(&s).unwrap();
+            assert_eq!(ty.head_word_count(), None, "{s}");
+        }
+    }
+
+    #[test]
+    fn function_types() {
+        let s = "function (uint256) internal pure returns (uint256)";
+        let ty: Type = syn::parse_str(s).unwrap();
+        let Type::Function(f) = &ty else { panic!("{ty:?}") };
+        assert!(f.attributes.has_internal());
+        assert_eq!(f.to_string(), s);
+        assert_eq!(ty.to_string(), s);
+        assert_eq!(ty.canonical_string(), "function");
+
+        let function: crate::ItemFunction =
+            syn::parse_str("function f(function (uint256) external callback) external;").unwrap();
+        let param = &function.parameters[0];
+        assert_eq!(param.name.as_ref().unwrap(), "callback");
+        let Type::Function(f) = &param.ty else { panic!("{param:?}") };
+        assert_eq!(f.to_string(), "function (uint256) external");
+        assert!(param.ty.is_abi_encodable());
+        assert_eq!(function.signature().unwrap(), "f(function)");
+
+        let function: crate::ItemFunction =
+            syn::parse_str("function f(function (uint256) callback) internal;").unwrap();
+        assert!(!function.parameters[0].ty.is_abi_encodable());
+        function.signature().unwrap_err();
+
+        let e = syn::parse_str::<Type>("function () public").unwrap_err();
+        assert_eq!(e.to_string(), "function types can only be declared `internal` or `external`");
+    }
+}
@@ -106,9 +106,63 @@ impl Spanned for TypeTuple {
     }
 }
 
+impl From<TypeTuple> for Type {
+    #[inline]
+    fn from(tuple: TypeTuple) -> Self {
+        Self::Tuple(tuple)
+    }
+}
+
+impl TryFrom<Type> for TypeTuple {
+    type Error = Type;
+
+    /// Converts a [`Type::Tuple`] into its inner tuple, returning the type
+    /// back if it is not a tuple.
+    #[inline]
+    fn try_from(ty: Type) -> std::result::Result<Self, Type> {
+        match ty {
+            Type::Tuple(tuple) => Ok(tuple),
+            ty => Err(ty),
+        }
+    }
+}
+
 impl TypeTuple {
     /// See [`Type::is_abi_dynamic`].
     pub fn is_abi_dynamic(&self) -> bool {
         self.types.iter().any(Type::is_abi_dynamic)
     }
+
+    /// Appends a type to the tuple, keeping the trailing comma required by
+    /// single element tuples.
+    pub fn push(&mut self, ty: Type) {
+        self.types.push(ty);
+        if self.types.len() == 1 {
+            self.types.push_punct(Default::default());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::num::NonZeroU16;
+
+    #[test]
+    fn push() {
+        let uint256 = Type::Uint(Span::call_site(), NonZeroU16::new(256));
+        let mut tuple: TypeTuple = std::iter::empty().collect();
+        tuple.push(uint256.clone());
+        assert!(tuple.types.trailing_punct());
+        assert_eq!(tuple.to_string(), "(uint256,)");
+        assert_eq!(syn::parse_str::<TypeTuple>(&tuple.to_string()).unwrap(), tuple);
+
+        tuple.push(Type::Bool(Span::call_site()));
+        assert_eq!(tuple.to_string(), "(uint256,bool)");
+
+        let ty = Type::from(tuple.clone());
+        assert_eq!(TypeTuple::try_from(ty).unwrap(), tuple);
+        assert_eq!(TypeTuple::try_from(uint256.clone()).unwrap_err(), uint256);
+    }
 }
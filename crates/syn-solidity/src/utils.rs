@@ -2,6 +2,7 @@ use crate::Expr;
 use proc_macro2::{TokenStream, TokenTree};
 use std::fmt;
 use syn::{parse::ParseStream, punctuated::Punctuated, Result, Token};
+use tiny_keccak::{Hasher, Keccak};
 
 /// Helper trait to parsing nested expressions.
 pub(crate) trait ParseNested: Sized {
@@ -33,3 +34,31 @@ pub(crate) fn tts_until_semi(input: ParseStream<'_>) -> TokenStream {
     }
     tts
 }
+
+/// Simple interface to the [`keccak256`] hash function.
+///
+/// [`keccak256`]: https://en.wikipedia.org/wiki/SHA-3
+pub(crate) fn keccak256<T: AsRef<[u8]>>(bytes: T) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    let mut hasher = Keccak::v256();
+    hasher.update(bytes.as_ref());
+    hasher.finalize(&mut output);
+    output
+}
+
+/// Formats `bytes` as a `0x`-prefixed lowercase hex string.
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    use fmt::Write;
+    let mut s = String::with_capacity(2 + bytes.len() * 2);
+    s.push_str("0x");
+    for byte in bytes {
+        write!(s, "{byte:02x}").unwrap();
+    }
+    s
+}
+
+/// Returns the first 4 bytes of the [`keccak256`] hash of `bytes`.
+pub(crate) fn selector<T: AsRef<[u8]>>(bytes: T) -> [u8; 4] {
+    let hash = keccak256(bytes);
+    [hash[0], hash[1], hash[2], hash[3]]
+}
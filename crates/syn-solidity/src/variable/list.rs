@@ -1,13 +1,14 @@
-use crate::{SolIdent, Spanned, Type, VariableDeclaration};
+use crate::{SolIdent, Spanned, Type, TypeTuple, VariableDeclaration, Visibility};
 use proc_macro2::Span;
 use std::{
     fmt,
     ops::{Deref, DerefMut},
 };
 use syn::{
-    parse::{Parse, ParseStream},
+    parenthesized,
+    parse::{Parse, ParseStream, Parser},
     punctuated::Punctuated,
-    Result, Token,
+    Error, Result, Token,
 };
 
 /// A list of comma-separated [VariableDeclaration]s.
@@ -170,6 +171,31 @@ impl<P> Parameters<P> {
         name
     }
 
+    /// Formats `name` and the canonical types of `self` as a Solidity
+    /// signature: `name(type1,type2,...)`.
+    ///
+    /// See [`Type::canonical_string`] for more details.
+    pub fn signature(&self, mut name: String) -> String {
+        name.reserve(2 + self.len() * 8);
+        name.push('(');
+        for (i, ty) in self.types().enumerate() {
+            if i > 0 {
+                name.push(',');
+            }
+            ty.canonical_string_raw(&mut name);
+        }
+        name.push(')');
+        name
+    }
+
+    /// Returns the JSON ABI representation of the parameters.
+    ///
+    /// See [`VariableDeclaration::to_abi_json`] for more details.
+    #[cfg(feature = "json")]
+    pub fn to_abi_json(&self) -> Vec<serde_json::Value> {
+        self.iter().map(VariableDeclaration::to_abi_json).collect()
+    }
+
     pub fn names(
         &self,
     ) -> impl ExactSizeIterator<Item = Option<&SolIdent>> + DoubleEndedIterator + Clone {
@@ -180,6 +206,96 @@ impl<P> Parameters<P> {
         self.iter().map(|var| &var.ty)
     }
 
+    /// Validates that no two parameters have the same name. Unnamed
+    /// parameters are ignored.
+    pub fn validate_unique_names(&self) -> crate::Result<()> {
+        self.check_unique_names().map_err(crate::Error::Validation)
+    }
+
+    fn check_unique_names(&self) -> Result<()> {
+        let mut names = Vec::<&SolIdent>::with_capacity(self.len());
+        for name in self.names().flatten() {
+            if let Some(prev) = names.iter().find(|prev| **prev == name) {
+                let msg = format!("duplicate parameter name `{}`", name.as_string());
+                let mut e = Error::new(name.span(), msg);
+                e.combine(Error::new(prev.span(), "previous declaration is here"));
+                return Err(e);
+            }
+            names.push(name);
+        }
+        Ok(())
+    }
+
+    /// Validates the data locations of the parameters of a function with the
+    /// given visibility.
+    ///
+    /// Value types cannot have a data location, and reference types (`string`,
+    /// `bytes`, arrays and mappings) must have one in `external` and `public`
+    /// functions. Custom types are not checked, since they may resolve to
+    /// either kind of type.
+    pub fn validate_locations(&self, visibility: Visibility) -> crate::Result<()> {
+        self.check_locations(visibility).map_err(crate::Error::Validation)
+    }
+
+    fn check_locations(&self, visibility: Visibility) -> Result<()> {
+        for param in self.iter() {
+            let ty = &param.ty;
+            match &param.storage {
+                Some(storage) if ty.is_value_type() => {
+                    let msg = format!(
+                        "data location can only be specified for array, struct or mapping types, \
+                         but `{storage}` was given for `{ty}`"
+                    );
+                    return Err(Error::new(storage.span(), msg));
+                }
+                None if matches!(visibility, Visibility::External(_) | Visibility::Public(_))
+                    && !ty.is_value_type()
+                    && !ty.is_custom() =>
+                {
+                    let msg = format!(
+                        "data location must be `memory` or `calldata` for parameter of type \
+                         `{ty}` in {visibility} function, but none was given"
+                    );
+                    return Err(Error::new(param.span(), msg));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Formats the parameters in the human-readable ABI format: canonical
+    /// types and names, without data locations.
+    pub(crate) fn human_readable(&self) -> String {
+        let params = self.iter().map(|param| {
+            let mut s = param.ty.canonical_string();
+            if let Some(name) = &param.name {
+                s.push(' ');
+                s.push_str(&name.as_string());
+            }
+            s
+        });
+        params.collect::<Vec<_>>().join(", ")
+    }
+
+    /// Returns the span of the parameter at `index`, covering its type and
+    /// name, or `None` if the index is out of bounds.
+    pub fn span_of(&self, index: usize) -> Option<Span> {
+        self.get(index).map(Spanned::span)
+    }
+
+    /// Returns the parameter types as a tuple. A single element tuple is
+    /// given a trailing comma: `(T,)`.
+    pub fn to_tuple(&self) -> TypeTuple {
+        self.types().cloned().collect()
+    }
+
+    /// Converts the parameters into a tuple of their types. See
+    /// [`to_tuple`](Self::to_tuple).
+    pub fn into_tuple(self) -> TypeTuple {
+        self.0.into_iter().map(|var| var.ty).collect()
+    }
+
     pub fn types_mut(&mut self) -> impl ExactSizeIterator<Item = &mut Type> + DoubleEndedIterator {
         self.iter_mut().map(|var| &mut var.ty)
     }
@@ -210,3 +326,164 @@ impl<P> Parameters<P> {
         }
     }
 }
+
+impl ParameterList {
+    /// Parses an anonymous ABI tuple string, like `(address,uint256)`, into
+    /// unnamed parameters. This is the reverse of the argument portion of
+    /// [`signature`](Self::signature).
+    ///
+    /// Unlike a [`TypeTuple`], the outer parentheses may contain a single type
+    /// without a trailing comma, or nothing at all. Nested tuples are parsed
+    /// as [`Type::Tuple`] parameters.
+    pub fn parse_abi_tuple(s: &str) -> crate::Result<Self> {
+        let parser = |input: ParseStream<'_>| {
+            let content;
+            parenthesized!(content in input);
+            let types = content.parse_terminated(Type::parse, Token![,])?;
+            Ok(types.into_iter().map(VariableDeclaration::new).collect())
+        };
+        parser.parse_str(s).map_err(crate::Error::Parse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ItemFunction, ParameterList, Spanned, Type, Visibility};
+    use pretty_assertions::assert_eq;
+    use proc_macro2::Span;
+    use syn::parse_quote;
+
+    #[test]
+    fn indexing() {
+        let f: ItemFunction = parse_quote! {
+            function f(uint a, bool b);
+        };
+        let params = &f.parameters;
+        assert_eq!(params.len(), 2);
+        assert!(!params.is_empty());
+        assert_eq!(params.get(1).map(|p| &p.ty), Some(&Type::Bool(Span::call_site())));
+        assert_eq!(params[0].ty, Type::Uint(Span::call_site(), None));
+        assert!(params.get(2).is_none());
+    }
+
+    #[test]
+    fn trailing_comma() {
+        let f: ItemFunction = parse_quote!(function f(uint256 a,););
+        assert_eq!(f.parameters.len(), 1);
+        assert!(f.parameters.trailing_punct());
+        assert_eq!(f.signature().unwrap(), "f(uint256)");
+        assert_eq!(f.to_string(), "function f(uint256 a);");
+
+        let f: ItemFunction = parse_quote!(function f(uint256 a, address b,););
+        assert_eq!(f.parameters.len(), 2);
+        assert_eq!(f.signature().unwrap(), "f(uint256,address)");
+
+        let f: ItemFunction = parse_quote!(function f(););
+        assert_eq!(f.parameters.len(), 0);
+        assert_eq!(f.signature().unwrap(), "f()");
+
+        syn::parse_str::<ItemFunction>("function f(,);").unwrap_err();
+        syn::parse_str::<ItemFunction>("function f(uint256 a,,);").unwrap_err();
+    }
+
+    #[test]
+    fn parse_abi_tuple() {
+        let params = ParameterList::parse_abi_tuple("(address,uint256)").unwrap();
+        assert_eq!(params.len(), 2);
+        assert!(params.names().all(|name| name.is_none()));
+        assert_eq!(params.type_strings().collect::<Vec<_>>(), ["address", "uint256"]);
+        assert_eq!(params.signature("f".into()), "f(address,uint256)");
+
+        let params = ParameterList::parse_abi_tuple("((uint8,uint8),bytes)").unwrap();
+        assert_eq!(params.len(), 2);
+        assert!(matches!(&params[0].ty, Type::Tuple(tuple) if tuple.types.len() == 2));
+        assert_eq!(params[1].ty, Type::Bytes(Span::call_site()));
+        assert_eq!(params.signature("g".into()), "g((uint8,uint8),bytes)");
+
+        assert_eq!(ParameterList::parse_abi_tuple("(bool)").unwrap().len(), 1);
+        assert!(ParameterList::parse_abi_tuple("()").unwrap().is_empty());
+        for s in ["address,uint256", "(address", "(address x)", "(address)[]"] {
+            ParameterList::parse_abi_tuple(s).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn tuples() {
+        let f: ItemFunction = parse_quote! {
+            function f(uint a, bool b, string memory c);
+        };
+        let types = f.parameters.types().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(types, ["uint", "bool", "string"]);
+        assert_eq!(f.parameters.to_tuple().to_string(), "(uint,bool,string)");
+        assert_eq!(f.parameters.into_tuple().to_string(), "(uint,bool,string)");
+
+        let f: ItemFunction = parse_quote!(function f(address a););
+        assert_eq!(f.parameters.to_tuple().to_string(), "(address,)");
+        assert_eq!(f.call_type().to_string(), "(address,)");
+        assert_eq!(f.parameters.into_tuple().to_string(), "(address,)");
+    }
+
+    #[test]
+    fn spans() {
+        let f: ItemFunction =
+            syn::parse_str("function f(uint a, string memory b, (bool, address) c);").unwrap();
+        let spans = (0..3).map(|i| f.parameters.span_of(i).unwrap()).collect::<Vec<_>>();
+        let columns =
+            spans.iter().map(|span| (span.start().column, span.end().column)).collect::<Vec<_>>();
+        assert_eq!(columns, [(11, 17), (19, 34), (36, 53)]);
+        assert_eq!(
+            spans[1].end().column,
+            f.parameters[1].name.as_ref().unwrap().span().end().column
+        );
+        assert!(f.parameters.span_of(3).is_none());
+    }
+
+    #[test]
+    fn locations() {
+        let external = Visibility::External(Default::default());
+        let internal = Visibility::Internal(Default::default());
+        let validate = |s: &str, visibility| {
+            let f: ItemFunction = syn::parse_str(s).unwrap();
+            f.parameters.validate_locations(visibility).map_err(|e| e.to_string())
+        };
+
+        assert_eq!(
+            validate("function f(string s) external;", external).unwrap_err(),
+            "data location must be `memory` or `calldata` for parameter of type `string` in \
+             external function, but none was given"
+        );
+        validate("function f(string s) internal;", internal).unwrap();
+        validate("function f(string calldata s, uint[] memory a, Custom c) external;", external)
+            .unwrap();
+
+        assert_eq!(
+            validate("function f(uint256 memory x) internal;", internal).unwrap_err(),
+            "data location can only be specified for array, struct or mapping types, but \
+             `memory` was given for `uint256`"
+        );
+        validate("function f(uint256 x, address y) external;", external).unwrap();
+    }
+
+    #[test]
+    fn unique_names() {
+        let validate = |s: &str| {
+            let f: ItemFunction = syn::parse_str(s).unwrap();
+            f.parameters.validate_unique_names().map_err(|e| e.to_string())
+        };
+        assert_eq!(
+            validate("function f(uint a, uint a);").unwrap_err(),
+            "duplicate parameter name `a`"
+        );
+        assert_eq!(
+            validate("function f(uint a, bool b, string memory a);").unwrap_err(),
+            "duplicate parameter name `a`"
+        );
+        validate("function f(uint, uint);").unwrap();
+        validate("function f(uint a, uint, uint b, uint);").unwrap();
+
+        let f: ItemFunction = syn::parse_str("function f(uint a, uint a);").unwrap();
+        let e = f.parameters.validate_unique_names().unwrap_err().into_syn_error();
+        let columns = e.into_iter().map(|e| e.span().start().column).collect::<Vec<_>>();
+        assert_eq!(columns, [24, 16]);
+    }
+}
@@ -1,10 +1,12 @@
-use crate::{Expr, SolIdent, Spanned, Storage, Type, VariableAttributes};
+use crate::{
+    kw, Expr, ItemFunction, SolIdent, Spanned, Storage, StructRegistry, Type, VariableAttributes,
+};
 use proc_macro2::Span;
 use std::fmt::{self, Write};
 use syn::{
     ext::IdentExt,
     parse::{Parse, ParseStream},
-    Attribute, Ident, Result, Token,
+    AttrStyle, Attribute, Error, Ident, Result, Token,
 };
 
 mod list;
@@ -78,28 +80,186 @@ impl VariableDeclaration {
 
     /// Formats `self` as an EIP-712 field: `<ty> <name>`
     pub fn fmt_eip712(&self, f: &mut impl Write) -> fmt::Result {
-        write!(f, "{}", self.ty)?;
+        fmt_eip712_type(&self.ty, f)?;
         if let Some(name) = &self.name {
             write!(f, " {name}")?;
         }
         Ok(())
     }
 
+    /// Returns the JSON ABI representation of `self`:
+    /// `{"name": ..., "type": ..., "internalType": ..., "components": [...]}`.
+    ///
+    /// `components` is only present for tuple types. Custom types cannot be
+    /// resolved without the surrounding context, so their name is used as-is.
+    #[cfg(feature = "json")]
+    pub fn to_abi_json(&self) -> serde_json::Value {
+        AbiParam::new(self.name.as_ref(), &self.ty).to_abi_json()
+    }
+
     pub fn parse_with_name(input: ParseStream<'_>) -> Result<Self> {
         Self::_parse(input, true)
     }
 
     fn _parse(input: ParseStream<'_>, require_name: bool) -> Result<Self> {
-        Ok(Self {
-            attrs: input.call(Attribute::parse_outer)?,
-            ty: input.parse()?,
-            storage: input.call(Storage::parse_opt)?,
-            name: if require_name || input.peek(Ident::peek_any) {
-                Some(input.parse()?)
-            } else {
-                None
-            },
-        })
+        let mut attrs = input.call(Attribute::parse_outer)?;
+        let ty = input.parse()?;
+        parse_doc_comments(input, &mut attrs)?;
+        let storage = input.call(Storage::parse_opt)?;
+        parse_doc_comments(input, &mut attrs)?;
+        if input.peek(kw::indexed) {
+            let msg = "the `indexed` keyword is only allowed in event parameters";
+            return Err(Error::new(input.span(), msg));
+        }
+        let name = if require_name || input.peek(Ident::peek_any) {
+            Some(input.call(SolIdent::parse_declaration)?)
+        } else {
+            None
+        };
+        parse_doc_comments(input, &mut attrs)?;
+        Ok(Self { attrs, ty, storage, name })
+    }
+}
+
+/// Parses doc comments in the middle of a declaration into `attrs`.
+///
+/// Regular comments are stripped by the lexer, but doc block comments like
+/// `uint256 /** amount */ x` are lexed as `#[doc = "..."]` attributes. Inner
+/// doc comments (`/*! ... */`) are converted to outer ones.
+fn parse_doc_comments(input: ParseStream<'_>, attrs: &mut Vec<Attribute>) -> Result<()> {
+    while input.peek(Token![#]) {
+        let start = attrs.len();
+        attrs.extend(input.call(Attribute::parse_inner)?.into_iter().map(|mut attr| {
+            attr.style = AttrStyle::Outer;
+            attr
+        }));
+        attrs.extend(input.call(Attribute::parse_outer)?);
+        if let Some(attr) = attrs[start..].iter().find(|attr| !attr.path().is_ident("doc")) {
+            return Err(Error::new_spanned(attr, "expected a type, storage location or name"));
+        }
+    }
+    Ok(())
+}
+
+/// Formats `ty` as an EIP-712 type name: elementary types are spelled
+/// canonically, like `uint256` for `uint`, and struct references by their
+/// unqualified name, like `Person` for `Mail.Person`.
+fn fmt_eip712_type(ty: &Type, f: &mut impl Write) -> fmt::Result {
+    match ty {
+        Type::Custom(path) => write!(f, "{}", path.last()),
+        Type::Array(array) => {
+            fmt_eip712_type(&array.ty, f)?;
+            let mut brackets = String::new();
+            array.write_brackets(&mut brackets);
+            f.write_str(&brackets)
+        }
+        ty => f.write_str(&ty.canonical_string()),
+    }
+}
+
+/// A parameter descriptor, as it appears in the JSON ABI.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AbiParam {
+    /// The name of the parameter, or an empty string if it is unnamed.
+    pub name: String,
+    /// The canonical type of the parameter, with tuples spelled as `tuple`:
+    /// `uint256`, `tuple[2]`.
+    pub ty: String,
+    /// The type of the parameter as it appears in the source code:
+    /// `struct Point[2]` for a resolved struct, otherwise the same as `ty`.
+    pub internal_type: String,
+    /// The components of tuple types, or of the elements of arrays of
+    /// tuples.
+    pub components: Option<Vec<AbiParam>>,
+}
+
+impl AbiParam {
+    /// Creates the descriptor of a parameter of type `ty`.
+    ///
+    /// Custom types cannot be resolved without the surrounding context, so
+    /// their name is used as-is. See [`new_in`](Self::new_in).
+    pub fn new(name: Option<&SolIdent>, ty: &Type) -> Self {
+        Self::new_raw(name.map(SolIdent::as_string).unwrap_or_default(), ty, None, &mut Vec::new())
+    }
+
+    /// Creates the descriptor of a parameter of type `ty`, resolving custom
+    /// types that name a struct in `structs` into tuples whose components
+    /// are named after the struct's fields.
+    ///
+    /// Recursive structs, like `struct Node { Node[] children; }`, cannot be
+    /// ABI-encoded. A struct that is referenced from within its own fields is
+    /// therefore not resolved again, and is kept as-is like an unknown type.
+    pub fn new_in(name: Option<&SolIdent>, ty: &Type, structs: &StructRegistry) -> Self {
+        let name = name.map(SolIdent::as_string).unwrap_or_default();
+        Self::new_raw(name, ty, Some(structs), &mut Vec::new())
+    }
+
+    /// `resolving` holds the names of the structs being resolved, to stop at
+    /// recursive references.
+    fn new_raw(
+        name: String,
+        ty: &Type,
+        structs: Option<&StructRegistry>,
+        resolving: &mut Vec<String>,
+    ) -> Self {
+        let mut ty_name = ty.canonical_string();
+        let mut internal_type = None;
+        let inner = ty.peel_arrays();
+        let components = match inner {
+            Type::Tuple(tuple) => Some(
+                tuple
+                    .types
+                    .iter()
+                    .map(|ty| Self::new_raw(String::new(), ty, structs, resolving))
+                    .collect(),
+            ),
+            Type::Custom(path) => {
+                let struct_name = path.last().as_string();
+                let strukt = structs
+                    .filter(|_| !resolving.contains(&struct_name))
+                    .and_then(|structs| structs.get(&struct_name));
+                strukt.map(|strukt| {
+                    internal_type = Some(format!("struct {path}"));
+                    resolving.push(struct_name);
+                    let components = strukt
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            let name = field.name.as_ref().map(SolIdent::as_string);
+                            Self::new_raw(name.unwrap_or_default(), &field.ty, structs, resolving)
+                        })
+                        .collect();
+                    resolving.pop();
+                    components
+                })
+            }
+            _ => None,
+        };
+        if components.is_some() {
+            // `(T1,T2)[2]` -> `tuple[2]`
+            let suffix = ty_name.split_off(inner.canonical_string().len());
+            if let Some(internal_type) = &mut internal_type {
+                internal_type.push_str(&suffix);
+            }
+            ty_name = format!("tuple{suffix}");
+        }
+        let internal_type = internal_type.unwrap_or_else(|| ty_name.clone());
+        Self { name, ty: ty_name, internal_type, components }
+    }
+
+    /// Returns the JSON ABI representation of `self`:
+    /// `{"name": ..., "type": ..., "internalType": ..., "components": [...]}`.
+    #[cfg(feature = "json")]
+    pub fn to_abi_json(&self) -> serde_json::Value {
+        let mut map = serde_json::Map::new();
+        map.insert("name".into(), self.name.clone().into());
+        map.insert("type".into(), self.ty.clone().into());
+        map.insert("internalType".into(), self.internal_type.clone().into());
+        if let Some(components) = &self.components {
+            let components = components.iter().map(Self::to_abi_json).collect();
+            map.insert("components".into(), serde_json::Value::Array(components));
+        }
+        map.into()
     }
 }
 
@@ -115,7 +275,11 @@ pub struct VariableDefinition {
 
 impl fmt::Display for VariableDefinition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {} {}", self.ty, self.attributes, self.name)?;
+        write!(f, "{} ", self.ty)?;
+        if !self.attributes.0.is_empty() {
+            write!(f, "{} ", self.attributes)?;
+        }
+        self.name.fmt(f)?;
         if let Some((_, _expr)) = &self.initializer {
             // TODO: fmt::Display for Expr
             write!(f, " = <expr>")?;
@@ -173,4 +337,96 @@ impl VariableDefinition {
             name: Some(self.name.clone()),
         }
     }
+
+    /// Returns the signature of the getter function that Solidity generates
+    /// for this variable if it is `public`, e.g. `balances(address)` for
+    /// `mapping(address => uint256) public balances;`.
+    ///
+    /// See [`ItemFunction::new_getter`] for more details.
+    pub fn getter_signature(&self) -> String {
+        ItemFunction::new_getter(self.name.clone(), self.ty.clone()).raw_signature()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn state_variables() {
+        let max: VariableDefinition = parse_quote! {
+            uint256 public constant MAX = 100;
+        };
+        assert!(max.attributes.has_public());
+        assert!(max.attributes.has_constant());
+        assert_eq!(max.name, "MAX");
+        assert!(max.initializer.is_some());
+        assert_eq!(max.getter_signature(), "MAX()");
+
+        let balances: VariableDefinition = parse_quote! {
+            mapping(address => mapping(uint => bool)[]) private balances;
+        };
+        assert!(balances.attributes.has_private());
+        assert!(balances.initializer.is_none());
+        assert_eq!(balances.getter_signature(), "balances(address,uint256,uint256)");
+    }
+
+    #[test]
+    fn state_variable_attributes() {
+        for (s, attrs) in [
+            ("uint256 public constant X = 1;", "public constant"),
+            ("uint256 constant public X = 1;", "public constant"),
+            ("address immutable internal owner;", "internal immutable"),
+            ("uint256 override(A, B) private immutable x;", "private immutable override(A, B)"),
+            ("uint256 public override totalSupply;", "public override"),
+            ("uint256 override(Base) public totalSupply;", "public override(Base)"),
+        ] {
+            let var: VariableDefinition = syn::parse_str(s).unwrap();
+            assert_eq!(var.attributes.to_string(), attrs, "{s}");
+        }
+
+        let var: VariableDefinition = syn::parse_str("address immutable internal owner;").unwrap();
+        assert!(var.attributes.has_immutable());
+        assert!(var.attributes.has_internal());
+
+        for s in ["uint256 constant immutable Y;", "uint256 immutable public constant Y;"] {
+            let e = syn::parse_str::<VariableDefinition>(s).unwrap_err();
+            assert_eq!(e.to_string(), "a variable cannot be both `constant` and `immutable`");
+        }
+        let e = syn::parse_str::<VariableDefinition>("uint256 public constant public Y;");
+        assert_eq!(e.unwrap_err().to_string(), "duplicate attribute");
+    }
+
+    #[test]
+    fn inline_comments() {
+        let decl: VariableDeclaration = syn::parse_str("uint256 /** amount */ x").unwrap();
+        assert_eq!(decl.to_string(), "uint256 x");
+        assert_eq!(decl.attrs.len(), 1);
+
+        let decl: VariableDeclaration =
+            syn::parse_str("bytes /*! data */ memory /** the data */ data /** end */").unwrap();
+        assert_eq!(decl.to_string(), "bytes memory data");
+        assert_eq!(decl.attrs.len(), 3);
+        assert!(decl.attrs.iter().all(|attr| attr.style == AttrStyle::Outer));
+
+        let err = syn::parse_str::<VariableDeclaration>("uint256 #[attr] x").unwrap_err();
+        assert_eq!(err.to_string(), "expected a type, storage location or name");
+    }
+
+    #[test]
+    fn indexed() {
+        for s in [
+            "function f(address indexed x);",
+            "function f(address indexed);",
+            "function f() returns (bytes memory indexed data);",
+        ] {
+            let e = syn::parse_str::<ItemFunction>(s).unwrap_err();
+            assert_eq!(e.to_string(), "the `indexed` keyword is only allowed in event parameters");
+        }
+
+        let event: crate::ItemEvent = syn::parse_str("event E(address indexed x);").unwrap();
+        assert!(event.parameters[0].is_indexed());
+    }
 }
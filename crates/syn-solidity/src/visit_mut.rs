@@ -19,3 +19,38 @@ make_visitor! {
     /// [module documentation]: self
     trait VisitMut is mut;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::num::NonZeroU16;
+
+    #[test]
+    fn canonicalize_integers() {
+        struct Canonicalize;
+
+        impl<'ast> VisitMut<'ast> for Canonicalize {
+            fn visit_type(&mut self, ty: &'ast mut Type) {
+                if let Type::Int(_, size @ None) | Type::Uint(_, size @ None) = ty {
+                    *size = NonZeroU16::new(256);
+                }
+                visit_type(self, ty);
+            }
+        }
+
+        let mut file: File = syn::parse_str(
+            "
+            struct S { uint a; int[] b; mapping(uint => int8) c; }
+            function f((uint, int) memory x) returns (uint);
+            ",
+        )
+        .unwrap();
+        Canonicalize.visit_file(&mut file);
+        assert_eq!(
+            file.to_string(),
+            "struct S { uint256 a; int256[] b; mapping(uint256 => int8) c; }\n\n\
+             function f((uint256,int256) memory x) returns (uint256);"
+        );
+    }
+}
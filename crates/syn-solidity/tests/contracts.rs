@@ -7,7 +7,7 @@ use std::{
 };
 use syn_solidity::{
     visit::{self, Visit},
-    File,
+    File, Type,
 };
 
 #[test]
@@ -98,6 +98,36 @@ impl Drop for GitPatcher<'_> {
     }
 }
 
+#[test]
+fn count_tuples() {
+    #[derive(Default)]
+    struct TupleCounter(usize);
+
+    impl<'ast> Visit<'ast> for TupleCounter {
+        fn visit_type(&mut self, ty: &'ast Type) {
+            if let Type::Tuple(_) = ty {
+                self.0 += 1;
+            }
+            visit::visit_type(self, ty);
+        }
+    }
+
+    let file: File = syn::parse_str(
+        "
+        struct S { (uint256, (bool, address))[] pairs; }
+        function f((uint256, bytes) memory a) returns ((bool,) memory);
+        contract C {
+            event E(uint256 indexed x, (string, string) y);
+            error Err(function ((uint8, uint8)) external cb);
+        }
+        ",
+    )
+    .unwrap();
+    let mut counter = TupleCounter::default();
+    counter.visit_file(&file);
+    assert_eq!(counter.0, 6);
+}
+
 fn parse_file(path: &Path) -> Result<File, Box<dyn std::error::Error>> {
     let solidity = fs::read_to_string(path)?;
     syn::parse_str(&solidity).map_err(Into::into)